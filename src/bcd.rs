@@ -7,15 +7,54 @@ use crate::{
     date_time::{
         Day,
         Hour,
+        HourMode,
         Minute,
         Second,
         Year,
     },
+    ranged::RangedU8,
     Error,
 };
-use deranged::RangedU8;
 use time::Month;
 
+/// Returns whether the RTC's seconds byte has its test flag set.
+///
+/// The S-3511A reports that it is in test mode using the most significant bit of the seconds byte.
+/// This is the only place that bit is interpreted.
+pub(crate) fn seconds_byte_is_test(byte: u8) -> bool {
+    byte & 0b1000_0000 != 0
+}
+
+/// Decodes the RTC's raw hour byte, reported in the given `HourMode`.
+///
+/// The most significant bit of the hour byte is the PM flag. In 12-hour mode, it is combined with
+/// the remaining bits, which are the hour within the half of the day, and an hour of `12` is
+/// interpreted the same as `0`. In 24-hour mode, the flag must not be set, and
+/// [`Error::AmPmBitPresent`] is returned if it is. The returned `Hour` is always in 24-hour form.
+///
+/// This is the only place the hour byte is interpreted.
+pub(crate) fn decode_hour(byte: u8, hour_mode: HourMode) -> Result<Hour, Error> {
+    let bcd = Bcd::try_from(byte)?;
+    let pm = bcd.0 & 0b1000_0000 != 0;
+    let hour = Bcd(bcd.0 & 0b0111_1111).to_binary().get();
+    match hour_mode {
+        HourMode::H12 => {
+            if hour > 12 {
+                return Err(Error::InvalidHour(hour));
+            }
+            let hour = hour % 12 + if pm { 12 } else { 0 };
+            // SAFETY: `hour` is guaranteed to be less than 24.
+            Ok(Hour(unsafe { RangedU8::new_unchecked(hour) }))
+        }
+        HourMode::H24 => {
+            if pm {
+                return Err(Error::AmPmBitPresent);
+            }
+            Ok(Hour(RangedU8::new(hour).ok_or(Error::InvalidHour(hour))?))
+        }
+    }
+}
+
 /// Binary coded decimal.
 ///
 /// The S-3511A stores values as BCD, meaning each half-byte represents a digit. For example, the
@@ -35,6 +74,34 @@ impl Bcd {
         // original value is guaranteed to be a valid BCD value.
         unsafe { RangedU8::new_unchecked(10 * (self.0 >> 4 & 0x0f) + (self.0 & 0x0f)) }
     }
+
+    /// Converts a binary value to its equivalent binary coded decimal form.
+    ///
+    /// The tens digit is packed into the high half-byte and the ones digit into the low half-byte.
+    /// This is the form in which values must be written to the RTC.
+    ///
+    /// No values are currently written to the RTC's date and time registers, so this is only
+    /// available to tests.
+    #[cfg(test)]
+    pub(crate) fn from_binary(value: RangedU8<0, 99>) -> Self {
+        let value = value.get();
+        Self(((value / 10) << 4) | (value % 10))
+    }
+}
+
+impl Bcd {
+    /// Checks that the BCD is a valid day of the week.
+    ///
+    /// The RTC counts the day of the week from `0` to `6`. The value is not otherwise used, but a
+    /// value outside of this range indicates that the surrounding read is corrupt.
+    pub(crate) fn validate_weekday(self) -> Result<(), Error> {
+        let value = self.to_binary().get();
+        if value > 6 {
+            Err(Error::InvalidWeekday(value))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// Directly wraps a byte as a BCD, or returns an error if the byte is not a valid BCD.
@@ -85,15 +152,7 @@ impl TryFrom<Bcd> for Hour {
     type Error = Error;
 
     fn try_from(bcd: Bcd) -> Result<Self, Self::Error> {
-        // Check for the am/pm bit.
-        if bcd.0 & 0b1000_0000 != 0 {
-            return Err(Error::AmPmBitPresent);
-        }
-        Ok(Self(
-            bcd.to_binary()
-                .narrow()
-                .ok_or(Error::InvalidHour(bcd.to_binary().get()))?,
-        ))
+        decode_hour(bcd.0, HourMode::H24)
     }
 }
 
@@ -116,7 +175,7 @@ impl TryFrom<Bcd> for Second {
 
     fn try_from(bcd: Bcd) -> Result<Self, Self::Error> {
         // Check for test bit.
-        if bcd.0 & 0b1000_0000 != 0 {
+        if seconds_byte_is_test(bcd.0) {
             return Err(Error::TestMode);
         }
         Ok(Self(
@@ -129,25 +188,64 @@ impl TryFrom<Bcd> for Second {
 
 #[cfg(test)]
 mod tests {
-    use super::Bcd;
+    use super::{
+        decode_hour,
+        seconds_byte_is_test,
+        Bcd,
+    };
     use crate::{
         date_time::{
             Day,
             Hour,
+            HourMode,
             Minute,
             Second,
             Year,
         },
+        ranged::RangedU8,
         Error,
     };
     use claims::{
         assert_err_eq,
+        assert_ok,
         assert_ok_eq,
     };
-    use deranged::RangedU8;
     use gba_test::test;
     use time::Month;
 
+    #[test]
+    fn from_binary_min() {
+        assert_eq!(Bcd::from_binary(RangedU8::new_static::<0>()), Bcd(0x00));
+    }
+
+    #[test]
+    fn from_binary_single_digit() {
+        assert_eq!(Bcd::from_binary(RangedU8::new_static::<9>()), Bcd(0x09));
+    }
+
+    #[test]
+    fn from_binary_two_digits() {
+        assert_eq!(Bcd::from_binary(RangedU8::new_static::<10>()), Bcd(0x10));
+    }
+
+    #[test]
+    fn from_binary() {
+        assert_eq!(Bcd::from_binary(RangedU8::new_static::<12>()), Bcd(0x12));
+    }
+
+    #[test]
+    fn from_binary_max() {
+        assert_eq!(Bcd::from_binary(RangedU8::new_static::<99>()), Bcd(0x99));
+    }
+
+    #[test]
+    fn from_binary_round_trip() {
+        for value in 0..=99 {
+            let value = RangedU8::<0, 99>::new(value).unwrap();
+            assert_eq!(Bcd::from_binary(value).to_binary(), value);
+        }
+    }
+
     #[test]
     fn to_binary() {
         assert_eq!(Bcd(0x12).to_binary(), RangedU8::<0, 99>::new_static::<12>());
@@ -261,6 +359,100 @@ mod tests {
         assert_err_eq!(Hour::try_from(Bcd(0x94)), Error::AmPmBitPresent);
     }
 
+    #[test]
+    fn decode_hour_12_am() {
+        assert_ok_eq!(
+            decode_hour(0x05, HourMode::H12),
+            Hour(RangedU8::new_static::<5>())
+        );
+    }
+
+    #[test]
+    fn decode_hour_12_pm() {
+        assert_ok_eq!(
+            decode_hour(0x81, HourMode::H12),
+            Hour(RangedU8::new_static::<13>())
+        );
+    }
+
+    #[test]
+    fn decode_hour_12_midnight() {
+        assert_ok_eq!(
+            decode_hour(0x00, HourMode::H12),
+            Hour(RangedU8::new_static::<0>())
+        );
+    }
+
+    #[test]
+    fn decode_hour_12_midnight_as_twelve() {
+        assert_ok_eq!(
+            decode_hour(0x12, HourMode::H12),
+            Hour(RangedU8::new_static::<0>())
+        );
+    }
+
+    #[test]
+    fn decode_hour_12_noon() {
+        assert_ok_eq!(
+            decode_hour(0x92, HourMode::H12),
+            Hour(RangedU8::new_static::<12>())
+        );
+    }
+
+    #[test]
+    fn decode_hour_12_fails_too_high() {
+        assert_err_eq!(decode_hour(0x13, HourMode::H12), Error::InvalidHour(13));
+    }
+
+    #[test]
+    fn decode_hour_24() {
+        assert_ok_eq!(
+            decode_hour(0x19, HourMode::H24),
+            Hour(RangedU8::new_static::<19>())
+        );
+    }
+
+    #[test]
+    fn decode_hour_24_fails_am_pm_bit() {
+        assert_err_eq!(decode_hour(0x81, HourMode::H24), Error::AmPmBitPresent);
+    }
+
+    #[test]
+    fn decode_hour_12_invalid_bcd() {
+        assert_err_eq!(
+            decode_hour(0x8a, HourMode::H12),
+            Error::InvalidBinaryCodedDecimal(0x8a)
+        );
+    }
+
+    #[test]
+    fn decode_hour_24_fails_too_high() {
+        assert_err_eq!(decode_hour(0x24, HourMode::H24), Error::InvalidHour(24));
+    }
+
+    #[test]
+    fn decode_hour_24_invalid_bcd() {
+        assert_err_eq!(
+            decode_hour(0x1a, HourMode::H24),
+            Error::InvalidBinaryCodedDecimal(0x1a)
+        );
+    }
+
+    #[test]
+    fn validate_weekday_min() {
+        assert_ok!(Bcd(0x00).validate_weekday());
+    }
+
+    #[test]
+    fn validate_weekday_max() {
+        assert_ok!(Bcd(0x06).validate_weekday());
+    }
+
+    #[test]
+    fn validate_weekday_fails_too_high() {
+        assert_err_eq!(Bcd(0x09).validate_weekday(), Error::InvalidWeekday(9));
+    }
+
     #[test]
     fn try_into_minute_single_digit() {
         assert_ok_eq!(
@@ -307,4 +499,19 @@ mod tests {
     fn try_into_second_fails_test_bit() {
         assert_err_eq!(Second::try_from(Bcd(0x80)), Error::TestMode);
     }
+
+    #[test]
+    fn seconds_byte_is_test_set() {
+        assert!(seconds_byte_is_test(0x85));
+        assert_err_eq!(Second::try_from(Bcd(0x85)), Error::TestMode);
+    }
+
+    #[test]
+    fn seconds_byte_is_test_clear() {
+        assert!(!seconds_byte_is_test(0x05));
+        assert_ok_eq!(
+            Second::try_from(Bcd(0x05)),
+            Second(RangedU8::new_static::<5>())
+        );
+    }
 }
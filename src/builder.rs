@@ -0,0 +1,355 @@
+//! Configuration of a `Clock` prior to its construction.
+
+use crate::{
+    date_time::HourMode,
+    gpio::{
+        enable,
+        is_test_mode,
+        reset,
+        set_status,
+        try_read_datetime_offset,
+        try_read_status,
+        try_read_status_lenient,
+        Status,
+    },
+    Clock,
+    Error,
+};
+use time::{
+    PrimitiveDateTime,
+    UtcOffset,
+};
+
+/// A builder for a configured [`Clock`].
+///
+/// This allows all configuration of a `Clock` to be specified up front, so that the RTC is only
+/// initialized once with the chosen settings. [`Clock::new()`] is equivalent to building with the
+/// default configuration.
+///
+/// # Example
+/// ``` no_run
+/// use gba_clock::ClockBuilder;
+/// use time::{
+///     Date,
+///     Month,
+///     PrimitiveDateTime,
+///     Time,
+///     UtcOffset,
+/// };
+///
+/// let current_time = PrimitiveDateTime::new(
+///     Date::from_calendar_date(2001, Month::March, 21).expect("invalid date"),
+///     Time::from_hms(11, 30, 0).expect("invalid time"),
+/// );
+/// let clock = ClockBuilder::new()
+///     .retries(2)
+///     .utc_offset(UtcOffset::from_hms(9, 0, 0).expect("invalid offset"))
+///     .build(current_time)
+///     .expect("could not communicate with the RTC");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ClockBuilder {
+    hour_mode: HourMode,
+    utc_offset: UtcOffset,
+    retries: u8,
+    lenient_status: bool,
+    reset_on_power_failure: bool,
+}
+
+impl ClockBuilder {
+    /// Creates a new builder with the default configuration.
+    ///
+    /// By default, the RTC is set to 24-hour mode, the stored time is assumed to be UTC, reads are
+    /// not retried, the RTC's status is parsed strictly, and the RTC is reset if it reports a power
+    /// failure.
+    pub const fn new() -> Self {
+        Self {
+            hour_mode: HourMode::H24,
+            utc_offset: UtcOffset::UTC,
+            retries: 0,
+            lenient_status: false,
+            reset_on_power_failure: true,
+        }
+    }
+
+    /// Sets whether the RTC reports hours in 24-hour mode.
+    ///
+    /// When disabled, the RTC is set to 12-hour mode. This only changes how the RTC reports hours
+    /// internally; all dates and times returned by the `Clock` are still in 24-hour form.
+    pub const fn hour_24(mut self, hour_24: bool) -> Self {
+        self.hour_mode = if hour_24 {
+            HourMode::H24
+        } else {
+            HourMode::H12
+        };
+        self
+    }
+
+    /// Sets the UTC offset of the stored date and time.
+    ///
+    /// See [`Clock::set_utc_offset()`] for more details.
+    pub const fn utc_offset(mut self, utc_offset: UtcOffset) -> Self {
+        self.utc_offset = utc_offset;
+        self
+    }
+
+    /// Sets the number of times reads are retried after failing with a transient error.
+    ///
+    /// See [`Clock::with_retries()`] for more details.
+    pub const fn retries(mut self, retries: u8) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Sets whether reserved bits in the RTC's status are ignored during initialization.
+    ///
+    /// By default, a status with any of its reserved bits set causes initialization to fail with
+    /// [`Error::InvalidStatus`]. Some RTC chips leave these bits set harmlessly; enabling this
+    /// clears them instead, allowing such chips to be initialized.
+    pub const fn lenient_status(mut self, lenient_status: bool) -> Self {
+        self.lenient_status = lenient_status;
+        self
+    }
+
+    /// Sets whether the RTC is reset if it reports a power failure during initialization.
+    ///
+    /// By default, an RTC whose power bit is set is silently reset, which is the most compatible
+    /// behavior with emulators. When disabled, initialization instead fails with
+    /// [`Error::PowerFailure`] without resetting the RTC, allowing the caller to decide how to
+    /// proceed. This is useful for battery-backed cartridges, where a power failure indicates the
+    /// battery has run dry.
+    ///
+    /// See also [`Clock::new_strict()`].
+    pub const fn reset_on_power_failure(mut self, reset_on_power_failure: bool) -> Self {
+        self.reset_on_power_failure = reset_on_power_failure;
+        self
+    }
+
+    /// Creates a builder with the same configuration as `clock`.
+    pub(crate) fn from_clock(clock: &Clock) -> Self {
+        Self {
+            hour_mode: clock.hour_mode,
+            utc_offset: clock.utc_offset,
+            retries: clock.retries,
+            lenient_status: clock.lenient_status,
+            reset_on_power_failure: true,
+        }
+    }
+
+    /// Creates a new `Clock` set at the given `datetime` using this configuration.
+    ///
+    /// This initializes the RTC exactly once, writing the configured hour mode to the RTC's status.
+    /// See [`Clock::new()`] for more details.
+    pub fn build(self, datetime: PrimitiveDateTime) -> Result<Clock, Error> {
+        // Enable operations with the RTC via General Purpose I/O (GPIO).
+        enable();
+
+        // A power failure must be checked for before the RTC is reset, as resetting clears it.
+        if !self.reset_on_power_failure {
+            check_power_failure(self.read_status()?)?;
+        }
+
+        // Initialize the RTC itself.
+        reset()?;
+        // If the power bit is active, we need to reset.
+        if self.read_status()?.contains(&Status::POWER) {
+            reset()?;
+        }
+        // If we are in test mode, we need to reset.
+        if is_test_mode()? {
+            reset()?;
+        }
+        // Set the hour mode.
+        set_status(match self.hour_mode {
+            HourMode::H12 => Status::EMPTY,
+            HourMode::H24 => Status::HOUR_24,
+        })?;
+
+        let rtc_offset = try_read_datetime_offset(self.hour_mode)?;
+
+        let mut clock = Clock::from_parts(datetime.date(), rtc_offset - datetime.time().into());
+        clock.hour_mode = self.hour_mode;
+        clock.utc_offset = self.utc_offset;
+        clock.retries = self.retries;
+        clock.lenient_status = self.lenient_status;
+        Ok(clock)
+    }
+}
+
+impl ClockBuilder {
+    /// Reads the RTC's status, ignoring reserved bits if configured to do so.
+    fn read_status(&self) -> Result<Status, Error> {
+        if self.lenient_status {
+            try_read_status_lenient()
+        } else {
+            try_read_status()
+        }
+    }
+}
+
+/// Returns [`Error::PowerFailure`] if `status` indicates the RTC has lost power.
+fn check_power_failure(status: Status) -> Result<(), Error> {
+    if status.contains(&Status::POWER) {
+        Err(Error::PowerFailure)
+    } else {
+        Ok(())
+    }
+}
+
+impl Default for ClockBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_power_failure,
+        ClockBuilder,
+    };
+    use crate::{
+        date_time::{
+            HourMode,
+            RtcDateTimeOffset,
+        },
+        ranged::RangedU32,
+        Clock,
+        Error,
+        Status,
+    };
+    use claims::{
+        assert_err_eq,
+        assert_ok,
+        assert_ok_eq,
+    };
+    use gba_test::test;
+    use time::UtcOffset;
+    use time_macros::{
+        date,
+        datetime,
+        offset,
+    };
+
+    #[test]
+    fn default() {
+        let builder = ClockBuilder::default();
+
+        assert_eq!(builder.hour_mode, HourMode::H24);
+        assert_eq!(builder.utc_offset, UtcOffset::UTC);
+        assert_eq!(builder.retries, 0);
+        assert!(!builder.lenient_status);
+        assert!(builder.reset_on_power_failure);
+    }
+
+    #[test]
+    fn reset_on_power_failure() {
+        assert!(
+            !ClockBuilder::new()
+                .reset_on_power_failure(false)
+                .reset_on_power_failure
+        );
+    }
+
+    #[test]
+    fn check_power_failure_ok() {
+        assert_ok!(check_power_failure(Status::HOUR_24));
+    }
+
+    #[test]
+    fn check_power_failure_power_bit() {
+        assert_err_eq!(
+            check_power_failure(Status::from_lenient(0b1100_0000)),
+            Error::PowerFailure
+        );
+    }
+
+    #[test]
+    fn hour_12() {
+        assert_eq!(ClockBuilder::new().hour_24(false).hour_mode, HourMode::H12);
+    }
+
+    #[test]
+    fn lenient_status() {
+        assert!(ClockBuilder::new().lenient_status(true).lenient_status);
+    }
+
+    #[test]
+    fn from_clock() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        clock.hour_mode = HourMode::H12;
+        clock.utc_offset = offset!(+9);
+        clock.retries = 2;
+        clock.lenient_status = true;
+
+        assert_eq!(
+            ClockBuilder::from_clock(&clock),
+            ClockBuilder::new()
+                .hour_24(false)
+                .utc_offset(offset!(+9))
+                .retries(2)
+                .lenient_status(true)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn build_default_matches_new() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let built = assert_ok!(ClockBuilder::new().build(datetime));
+        let new = assert_ok!(Clock::new(datetime));
+
+        assert_eq!(built.base_date, new.base_date);
+        assert_eq!(built.hour_mode, new.hour_mode);
+        assert_eq!(built.utc_offset, new.utc_offset);
+        assert_eq!(built.retries, new.retries);
+        assert_ok_eq!(built.read_datetime(), datetime);
+        assert_ok_eq!(new.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn build_lenient_status() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(ClockBuilder::new().lenient_status(true).build(datetime));
+
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn build_no_reset_on_power_failure() {
+        let datetime = datetime!(2012-12-21 5:23);
+        // Ensure the RTC has been initialized, clearing any power failure.
+        assert_ok!(Clock::new(datetime));
+        let clock = assert_ok!(ClockBuilder::new()
+            .reset_on_power_failure(false)
+            .build(datetime));
+
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn build_hour_12() {
+        let datetime = datetime!(2012-12-21 17:23);
+        let clock = assert_ok!(ClockBuilder::new().hour_24(false).build(datetime));
+
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+}
@@ -0,0 +1,542 @@
+//! A view of a `Clock` that reuses previously calculated dates and times.
+
+use crate::{
+    apply_display_shift,
+    date_time::RtcDateTimeOffset,
+    Clock,
+    Error,
+};
+use core::cell::Cell;
+use time::{
+    Date,
+    PrimitiveDateTime,
+};
+
+/// A view of a [`Clock`] that reuses previously calculated dates and times.
+///
+/// This is returned by [`Clock::cached()`]. Calculating a date from the RTC's value requires
+/// software division on the GBA, which adds up when the date and time are polled many times per
+/// second. A `CachedClock` remembers its previous results and returns them again while they are
+/// still correct. The RTC is still read on every call.
+///
+/// The `Clock` is borrowed for as long as the `CachedClock` exists, so the stored date and time
+/// can't change while a remembered result is in use. The remembered results are stored in
+/// [`Cell`]s, meaning `CachedClock` does not implement [`Sync`]. The `Clock` itself is unaffected.
+#[derive(Debug)]
+pub struct CachedClock<'a> {
+    /// The `Clock` being viewed.
+    clock: &'a Clock,
+
+    /// The most recent date calculated by [`CachedClock::read_date()`].
+    date: Cell<Option<CachedDate>>,
+
+    /// The number of times [`CachedClock::read_date()`] reused the cached date.
+    hits: Cell<u32>,
+
+    /// The number of times [`CachedClock::read_date()`] recalculated the date.
+    misses: Cell<u32>,
+
+    /// The most recent date and time calculated by [`CachedClock::read_datetime()`].
+    datetime: Cell<Option<CachedDateTime>>,
+
+    /// The most recent date and time returned by [`CachedClock::read_monotonic()`].
+    last_monotonic: Cell<Option<PrimitiveDateTime>>,
+}
+
+/// A date calculated by [`CachedClock::read_date()`], along with the minute it was calculated in.
+#[derive(Clone, Copy, Debug)]
+struct CachedDate {
+    /// The number of whole minutes elapsed since the stored base date when this date was
+    /// calculated.
+    minute: u32,
+    /// The calculated date.
+    date: Date,
+}
+
+/// A date and time calculated by [`CachedClock::read_datetime()`], along with the RTC value it
+/// was calculated from.
+#[derive(Clone, Copy, Debug)]
+struct CachedDateTime {
+    /// The value read from the RTC that this date and time was calculated from.
+    read_offset: RtcDateTimeOffset,
+    /// The calculated date and time.
+    datetime: PrimitiveDateTime,
+}
+
+impl<'a> CachedClock<'a> {
+    /// Creates a caching view of `clock`, with nothing cached.
+    pub(crate) fn new(clock: &'a Clock) -> Self {
+        Self {
+            clock,
+            date: Cell::new(None),
+            hits: Cell::new(0),
+            misses: Cell::new(0),
+            datetime: Cell::new(None),
+            last_monotonic: Cell::new(None),
+        }
+    }
+
+    /// Returns the `Clock` being viewed.
+    pub fn clock(&self) -> &'a Clock {
+        self.clock
+    }
+
+    /// Reads the currently stored date, reusing the previously calculated date while the stored
+    /// time remains within the same minute.
+    ///
+    /// This returns the same value as [`Clock::read_date()`]. The date cannot change without
+    /// crossing midnight, so the RTC is only read to determine the current minute, and the date
+    /// arithmetic is skipped when it matches the minute of the previous call.
+    ///
+    /// The cache is not used while a display shift is set.
+    pub fn read_date(&self) -> Result<Date, Error> {
+        if let Some(datetime) = self.clock.shifted_detached() {
+            return datetime.map(PrimitiveDateTime::date);
+        }
+        let rtc_offset = self.clock.read_offset()?;
+        if self.clock.display_shift.is_zero() {
+            self.date_at(rtc_offset)
+        } else {
+            Ok(self.clock.shifted_datetime_at(rtc_offset)?.date())
+        }
+    }
+
+    /// Calculates the stored date at the point the RTC reads `rtc_offset`, reusing the result of
+    /// the previous call if it was within the same minute.
+    fn date_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<Date, Error> {
+        let minute = (rtc_offset - self.clock.rtc_offset).0.get() / 60;
+        if let Some(cached) = self.date.get() {
+            if cached.minute == minute {
+                self.hits.set(self.hits.get().wrapping_add(1));
+                return Ok(cached.date);
+            }
+        }
+
+        self.misses.set(self.misses.get().wrapping_add(1));
+        let date = self.clock.date_at(rtc_offset)?;
+        self.date.set(Some(CachedDate { minute, date }));
+        Ok(date)
+    }
+
+    /// Reads the currently stored date and time, reusing the previous result if the RTC has not
+    /// advanced.
+    ///
+    /// This returns the same value as [`Clock::read_datetime()`]. The last date and time returned
+    /// is remembered along with the RTC value it was calculated from. If the RTC still reads the
+    /// same value (meaning the same second), the remembered date and time is returned without
+    /// repeating the date arithmetic.
+    pub fn read_datetime(&self) -> Result<PrimitiveDateTime, Error> {
+        if let Some(datetime) = self.clock.shifted_detached() {
+            return datetime;
+        }
+        let rtc_offset = self.clock.read_offset()?;
+        apply_display_shift(self.datetime_at(rtc_offset)?, self.clock.display_shift)
+    }
+
+    /// Calculates the stored date and time at the point the RTC reads `rtc_offset`, reusing the
+    /// result of the previous call if `rtc_offset` is unchanged.
+    fn datetime_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<PrimitiveDateTime, Error> {
+        if let Some(cached) = self.datetime.get() {
+            if cached.read_offset == rtc_offset {
+                return Ok(cached.datetime);
+            }
+        }
+
+        let datetime = self.clock.datetime_at(rtc_offset)?;
+        self.datetime.set(Some(CachedDateTime {
+            read_offset: rtc_offset,
+            datetime,
+        }));
+        Ok(datetime)
+    }
+
+    /// Reads the currently stored date and time, never returning a value earlier than a previous
+    /// call.
+    ///
+    /// The RTC does not observe leap seconds, so successive reads normally never go backward.
+    /// However, this guarantees it: the last returned date and time is remembered, and if a new
+    /// read would be earlier, the previously returned value is returned instead. This is useful
+    /// for timers that must never observe a negative delta.
+    ///
+    /// The last returned value is only remembered by this `CachedClock`. Changing the stored date
+    /// and time requires ending the borrow of the `Clock`, after which a new `CachedClock` starts
+    /// with nothing remembered.
+    pub fn read_monotonic(&self) -> Result<PrimitiveDateTime, Error> {
+        Ok(self.clamp_monotonic(self.clock.read_datetime()?))
+    }
+
+    /// Returns `datetime`, or the last value returned by [`CachedClock::read_monotonic()`] if it
+    /// is later, remembering the result.
+    fn clamp_monotonic(&self, datetime: PrimitiveDateTime) -> PrimitiveDateTime {
+        let datetime = match self.last_monotonic.get() {
+            Some(last) if last > datetime => last,
+            _ => datetime,
+        };
+        self.last_monotonic.set(Some(datetime));
+        datetime
+    }
+
+    /// Discards any cached date and time.
+    ///
+    /// The next call to [`CachedClock::read_date()`] or [`CachedClock::read_datetime()`] will
+    /// recalculate its result from the value read from the RTC, rather than reusing a previous
+    /// result. The value remembered by [`CachedClock::read_monotonic()`] is kept.
+    pub fn invalidate_cache(&mut self) {
+        self.date.set(None);
+        self.datetime.set(None);
+    }
+
+    /// Returns the number of times [`CachedClock::read_date()`] has reused the cached date.
+    ///
+    /// The counter wraps on overflow.
+    ///
+    /// This counter is intended for diagnostics, such as verifying that a polling loop benefits
+    /// from the cache. It is not part of the stable interface, and may be compiled out in the
+    /// future.
+    pub fn cache_hit_count(&self) -> u32 {
+        self.hits.get()
+    }
+
+    /// Returns the number of times [`CachedClock::read_date()`] has recalculated the date.
+    ///
+    /// The counter wraps on overflow.
+    ///
+    /// This counter is intended for diagnostics, such as verifying that a polling loop benefits
+    /// from the cache. It is not part of the stable interface, and may be compiled out in the
+    /// future.
+    pub fn cache_miss_count(&self) -> u32 {
+        self.misses.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedClock;
+    use crate::{
+        date_time::{
+            RtcDateTimeOffset,
+            RTC_MAX_SECONDS,
+        },
+        gpio,
+        ranged::RangedU32,
+        Clock,
+        Error,
+    };
+    use claims::{
+        assert_err_eq,
+        assert_none,
+        assert_ok,
+        assert_ok_eq,
+        assert_some,
+    };
+    use gba_test::test;
+    use time::Duration;
+    use time_macros::{
+        date,
+        datetime,
+    };
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_date() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+        let cached = clock.cached();
+
+        assert_ok_eq!(cached.read_date(), datetime.date());
+        assert_ok_eq!(cached.read_date(), datetime.date());
+    }
+
+    #[test]
+    fn date_at_century_wrap() {
+        let clock = Clock::from_parts(
+            date!(2098 - 12 - 31),
+            RtcDateTimeOffset(assert_some!(RangedU32::new(RTC_MAX_SECONDS - 86_399))),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::MAX)),
+            date!(2098 - 12 - 31)
+        );
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<3_600>())),
+            date!(2099 - 01 - 01)
+        );
+    }
+
+    #[test]
+    fn date_at_same_minute_hit() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<200>())),
+            date!(2012 - 12 - 21)
+        );
+
+        // Replace the cached date to observe whether it is reused.
+        let mut date = assert_some!(cached.date.get());
+        date.date = date!(2000 - 01 - 01);
+        cached.date.set(Some(date));
+
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<219>())),
+            date!(2000 - 01 - 01)
+        );
+    }
+
+    #[test]
+    fn date_at_different_minute_miss() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<200>())),
+            date!(2012 - 12 - 21)
+        );
+
+        // Replace the cached date to observe whether it is reused.
+        let mut date = assert_some!(cached.date.get());
+        date.date = date!(2000 - 01 - 01);
+        cached.date.set(Some(date));
+
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<220>())),
+            date!(2012 - 12 - 21)
+        );
+    }
+
+    #[test]
+    fn date_at_crossed_midnight_miss() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<86_499>())),
+            date!(2012 - 12 - 21)
+        );
+        assert_ok_eq!(
+            cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<86_500>())),
+            date!(2012 - 12 - 22)
+        );
+    }
+
+    #[test]
+    fn date_at_counts() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_ok!(cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<200>())));
+        assert_ok!(cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<210>())));
+        assert_ok!(cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<219>())));
+        assert_eq!(cached.cache_hit_count(), 2);
+        assert_eq!(cached.cache_miss_count(), 1);
+
+        assert_ok!(cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<220>())));
+        assert_eq!(cached.cache_hit_count(), 2);
+        assert_eq!(cached.cache_miss_count(), 2);
+    }
+
+    #[test]
+    fn invalidate_cache_forces_miss() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+        let mut cached = CachedClock::new(&clock);
+
+        assert_ok!(cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<200>())));
+        assert_ok!(cached.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<200>())));
+        cached.invalidate_cache();
+        assert_none!(cached.date.get());
+        assert_none!(cached.datetime.get());
+
+        assert_ok!(cached.date_at(RtcDateTimeOffset(RangedU32::new_static::<200>())));
+        assert_eq!(cached.cache_hit_count(), 0);
+        assert_eq!(cached.cache_miss_count(), 2);
+    }
+
+    #[test]
+    fn datetime_at_same_offset_hit() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_ok_eq!(
+            cached.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<200>())),
+            datetime!(2012-12-21 0:01:40)
+        );
+
+        // Replace the cached date and time to observe whether it is reused.
+        let mut datetime = assert_some!(cached.datetime.get());
+        datetime.datetime = datetime!(2000-01-01 0:00);
+        cached.datetime.set(Some(datetime));
+
+        assert_ok_eq!(
+            cached.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<200>())),
+            datetime!(2000-01-01 0:00)
+        );
+    }
+
+    #[test]
+    fn datetime_at_changed_offset_miss() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_ok_eq!(
+            cached.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<200>())),
+            datetime!(2012-12-21 0:01:40)
+        );
+
+        // Replace the cached date and time to observe whether it is reused.
+        let mut datetime = assert_some!(cached.datetime.get());
+        datetime.datetime = datetime!(2000-01-01 0:00);
+        cached.datetime.set(Some(datetime));
+
+        assert_ok_eq!(
+            cached.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<201>())),
+            datetime!(2012-12-21 0:01:41)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+        let cached = clock.cached();
+
+        assert_ok_eq!(cached.read_datetime(), datetime);
+        assert_ok_eq!(cached.read_datetime(), assert_ok!(clock.read_datetime()));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+        let cached = clock.cached();
+
+        assert_ok_eq!(cached.read_datetime(), datetime!(2012-12-21 6:23));
+        // A remembered value is shifted as well.
+        assert_ok_eq!(cached.read_datetime(), assert_ok!(clock.read_datetime()));
+    }
+
+    #[test]
+    fn read_datetime_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.cached().read_datetime(), datetime!(2012-12-20 23:23));
+    }
+
+    #[test]
+    fn read_datetime_software() {
+        let clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        assert_ok_eq!(clock.cached().read_datetime(), datetime!(2012-12-21 5:23));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_monotonic() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(clock.cached().read_monotonic(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_monotonic_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.cached().read_monotonic(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn clamp_monotonic_forward() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_eq!(
+            cached.clamp_monotonic(datetime!(2012-12-21 5:23)),
+            datetime!(2012-12-21 5:23)
+        );
+        assert_eq!(
+            cached.clamp_monotonic(datetime!(2012-12-21 5:24)),
+            datetime!(2012-12-21 5:24)
+        );
+    }
+
+    #[test]
+    fn clamp_monotonic_backward() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        let cached = CachedClock::new(&clock);
+
+        assert_eq!(
+            cached.clamp_monotonic(datetime!(2012-12-21 5:23)),
+            datetime!(2012-12-21 5:23)
+        );
+        assert_eq!(
+            cached.clamp_monotonic(datetime!(2012-12-21 5:22)),
+            datetime!(2012-12-21 5:23)
+        );
+        // Once the time catches up, it is returned again.
+        assert_eq!(
+            cached.clamp_monotonic(datetime!(2012-12-21 5:24)),
+            datetime!(2012-12-21 5:24)
+        );
+    }
+}
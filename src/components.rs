@@ -0,0 +1,98 @@
+//! A date and time decomposed into its individual fields.
+
+use crate::Error;
+use time::PrimitiveDateTime;
+
+/// The individual fields of a date and time read from a [`Clock`](crate::Clock).
+///
+/// This is returned by [`Clock::read_components()`](crate::Clock::read_components()), and is a
+/// plain decomposition of the [`PrimitiveDateTime`] returned by
+/// [`Clock::read_datetime()`](crate::Clock::read_datetime()).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DateTimeComponents {
+    /// The year.
+    pub year: u16,
+    /// The month, in the range `1..=12`.
+    pub month: u8,
+    /// The day of the month, in the range `1..=31`.
+    pub day: u8,
+    /// The hour, in the range `0..=23`.
+    pub hour: u8,
+    /// The minute, in the range `0..=59`.
+    pub minute: u8,
+    /// The second, in the range `0..=59`.
+    pub second: u8,
+    /// The day of the week, in the range `0..=6`, where `0` is Sunday.
+    pub weekday: u8,
+}
+
+/// Decomposes a date and time into its individual fields.
+///
+/// Returns [`Error::Underflow`] if the year is negative, as it cannot be represented as a `u16`.
+impl TryFrom<PrimitiveDateTime> for DateTimeComponents {
+    type Error = Error;
+
+    fn try_from(datetime: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Self {
+            year: u16::try_from(datetime.year()).map_err(|_| Error::Underflow)?,
+            month: datetime.month().into(),
+            day: datetime.day(),
+            hour: datetime.hour(),
+            minute: datetime.minute(),
+            second: datetime.second(),
+            weekday: datetime.weekday().number_days_from_sunday(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DateTimeComponents;
+    use crate::Error;
+    use claims::{
+        assert_err_eq,
+        assert_ok_eq,
+    };
+    use gba_test::test;
+    use time_macros::datetime;
+
+    #[test]
+    fn try_from() {
+        assert_ok_eq!(
+            DateTimeComponents::try_from(datetime!(2012-12-21 5:23:42)),
+            DateTimeComponents {
+                year: 2012,
+                month: 12,
+                day: 21,
+                hour: 5,
+                minute: 23,
+                second: 42,
+                weekday: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_sunday() {
+        assert_ok_eq!(
+            DateTimeComponents::try_from(datetime!(2000-01-02 0:00)),
+            DateTimeComponents {
+                year: 2000,
+                month: 1,
+                day: 2,
+                hour: 0,
+                minute: 0,
+                second: 0,
+                weekday: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_negative_year() {
+        assert_err_eq!(
+            DateTimeComponents::try_from(datetime!(-0001-01-01 0:00)),
+            Error::Underflow
+        );
+    }
+}
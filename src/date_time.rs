@@ -1,5 +1,12 @@
 //! Types and logic for representing and understanding the date and time stored within the RTC.
 
+use crate::{
+    ranged::{
+        RangedU32,
+        RangedU8,
+    },
+    Error,
+};
 use core::{
     fmt,
     fmt::Debug,
@@ -9,10 +16,6 @@ use core::{
         SubAssign,
     },
 };
-use deranged::{
-    RangedU32,
-    RangedU8,
-};
 #[cfg(feature = "serde")]
 use serde::{
     de::{
@@ -29,9 +32,130 @@ use time::{
     Date,
     Duration,
     Month,
+    PrimitiveDateTime,
     Time,
 };
 
+/// The year corresponding to a stored year of `00`.
+///
+/// The RTC stores only the last two digits of the year, which are interpreted as being relative to
+/// this year. Raw RTC values are therefore counted from the start of this year.
+pub const RTC_ORIGIN_YEAR: i32 = 2000;
+
+/// Returns the date and time represented by a raw RTC value of zero.
+///
+/// This is midnight on January 1st of [`RTC_ORIGIN_YEAR`].
+pub fn rtc_origin() -> PrimitiveDateTime {
+    // SAFETY: January 1st exists in every year.
+    unsafe { Date::from_calendar_date(RTC_ORIGIN_YEAR, Month::January, 1).unwrap_unchecked() }
+        .midnight()
+}
+
+/// Checks that the given year is within the range representable by the RTC.
+///
+/// The RTC stores only the last two digits of the year, meaning it can only represent years within
+/// the range 2000-2099.
+pub(crate) fn validate_year(year: i32) -> Result<(), Error> {
+    if (RTC_ORIGIN_YEAR..=RTC_ORIGIN_YEAR + 99).contains(&year) {
+        Ok(())
+    } else {
+        Err(Error::DateOutOfRange)
+    }
+}
+
+/// Returns whether the given year is a leap year in the Gregorian calendar.
+pub(crate) const fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Returns whether `date` is February 29.
+///
+/// A `Date` is always valid, so February 29 can only occur in a leap year and no separate leap
+/// year check is needed.
+pub(crate) fn is_leap_day(date: Date) -> bool {
+    date.month() == Month::February && date.day() == 29
+}
+
+/// Returns the ISO year and ISO week number of `date`.
+///
+/// The week number is in the range `1..=53`. Dates early in January may belong to the last week of
+/// the previous ISO year, and dates late in December may belong to the first week of the next ISO
+/// year.
+pub(crate) fn iso_week(date: Date) -> (i32, u8) {
+    let (year, week, _) = date.to_iso_week_date();
+    (year, week)
+}
+
+/// Returns the number of days in `month` of `year`.
+///
+/// This accounts for leap years, returning 29 for February of a leap year and 28 otherwise. This
+/// is useful for bounding user input when setting the day of the month.
+pub const fn days_in_month(month: Month, year: u16) -> u8 {
+    match month {
+        Month::January
+        | Month::March
+        | Month::May
+        | Month::July
+        | Month::August
+        | Month::October
+        | Month::December => 31,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        Month::February => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+    }
+}
+
+/// Replaces the year of `date`, preserving its month and day.
+///
+/// If `date` is February 29 and `year` is not a leap year, the day is clamped to February 28.
+/// Returns [`Error::DateOutOfRange`] if `year` is not within the range representable by the RTC.
+#[cfg(not(feature = "read-only"))]
+pub(crate) fn replace_year(date: Date, year: u16) -> Result<Date, Error> {
+    validate_year(year.into())?;
+    let day = if date.month() == Month::February && date.day() == 29 && !is_leap_year(year) {
+        28
+    } else {
+        date.day()
+    };
+    Date::from_calendar_date(year.into(), date.month(), day).map_err(|_| Error::InvalidDay(day))
+}
+
+/// Replaces the month of `date`, preserving its year and day.
+///
+/// If the day does not exist in the new month, it is clamped to the last day of the month.
+/// Returns [`Error::InvalidMonth`] if `month` is not within the range `1..=12`.
+#[cfg(not(feature = "read-only"))]
+pub(crate) fn replace_month(date: Date, month: u8) -> Result<Date, Error> {
+    let month = Month::try_from(month).map_err(|_| Error::InvalidMonth(month))?;
+    // The leap year rule repeats every 400 years, so this gives the correct result for any year.
+    let day = date
+        .day()
+        .min(days_in_month(month, date.year().rem_euclid(400) as u16));
+    Date::from_calendar_date(date.year(), month, day).map_err(|_| Error::InvalidDay(day))
+}
+
+/// Replaces the day of `date`, preserving its year and month.
+///
+/// Returns [`Error::InvalidDay`] if `day` does not exist in the month of `date`.
+#[cfg(not(feature = "read-only"))]
+pub(crate) fn replace_day(date: Date, day: u8) -> Result<Date, Error> {
+    date.replace_day(day).map_err(|_| Error::InvalidDay(day))
+}
+
+/// The format in which the RTC reports hours.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum HourMode {
+    /// Hours are reported within the range 0-11 with a PM flag.
+    H12,
+    /// Hours are reported within the range 0-23.
+    H24,
+}
+
 /// A calendar year.
 ///
 /// Specifically, this is the last two digits of the year. It represents a year in the range
@@ -55,11 +179,97 @@ pub(crate) struct Minute(pub(crate) RangedU8<0, 59>);
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) struct Second(pub(crate) RangedU8<0, 59>);
 
-#[derive(Clone, Copy, Eq, PartialEq)]
-pub(crate) struct RtcDateTimeOffset(pub(crate) RangedU32<0, 3_155_759_999>);
+/// Checks that the given day exists within the given month and year.
+///
+/// The RTC only bounds the day to the range 1-31, so it is possible for it to report a date that
+/// does not exist, such as February 30th.
+pub(crate) fn validate_date(year: &Year, month: Month, day: &Day) -> Result<(), Error> {
+    let full_year = RTC_ORIGIN_YEAR as u16 + u16::from(year.0.get());
+    if day.0.get() <= days_in_month(month, full_year) {
+        Ok(())
+    } else {
+        Err(Error::InvalidDate {
+            year: full_year,
+            month: month.into(),
+            day: day.0.get(),
+        })
+    }
+}
+
+/// The maximum raw date and time value the RTC can store.
+///
+/// This is the number of seconds from 2000-01-01 00:00:00 to 2099-12-31 23:59:59.
+pub(crate) const RTC_MAX_SECONDS: u32 = 3_155_759_999;
+
+// Ensure the maximum value agrees with the date calculation used to construct offsets.
+const _: () = assert!(
+    RtcDateTimeOffset::new(
+        Year(RangedU8::MAX),
+        Month::December,
+        Day(RangedU8::MAX),
+        Hour(RangedU8::MAX),
+        Minute(RangedU8::MAX),
+        Second(RangedU8::MAX)
+    )
+    .0
+    .get()
+        == RTC_MAX_SECONDS
+);
+
+/// A raw date and time value stored within the RTC.
+///
+/// This is the number of seconds elapsed since 2000-01-01 00:00:00 according to the RTC's
+/// clock. As the RTC stores only two digits for the year, this value wraps around after
+/// 2099-12-31 23:59:59.
+///
+/// Offsets can be precomputed from known dates and times using the [`TryFrom<PrimitiveDateTime>`]
+/// implementation, and can be used to construct a [`Clock`](crate::Clock) using
+/// [`Clock::from_parts()`](crate::Clock::from_parts()).
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RtcDateTimeOffset(pub(crate) RangedU32<0, RTC_MAX_SECONDS>);
 
 impl RtcDateTimeOffset {
-    pub(crate) fn new(
+    /// Returns the number of seconds elapsed since 2000-01-01 00:00:00.
+    pub fn get(self) -> u32 {
+        self.0.get()
+    }
+
+    /// Adds `other`, clamping at the maximum value rather than wrapping.
+    ///
+    /// The `AddAssign` implementation wraps around to zero past 2099-12-31 23:59:59, which models
+    /// the RTC's own circular counter. This instead returns the maximum value whenever the sum
+    /// would exceed it, which is appropriate for adjustments that are not tied to the RTC.
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self(self.0.checked_add(other.0.get()).unwrap_or(RangedU32::MAX))
+    }
+
+    /// Subtracts `other`, clamping at zero rather than wrapping.
+    ///
+    /// The `Sub` implementation wraps around to the maximum value below zero, which models the
+    /// RTC's own circular counter. This instead returns zero whenever the difference would be
+    /// negative, which is appropriate for adjustments that are not tied to the RTC.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self(self.0.checked_sub(other.0.get()).unwrap_or(RangedU32::MIN))
+    }
+
+    /// Adds `secs` seconds, returning `None` if the result would be past 2099-12-31 23:59:59.
+    ///
+    /// Unlike the wrapping `AddAssign` implementation, this allows detecting when an adjustment
+    /// would leave the RTC's range.
+    pub fn checked_add(self, secs: u32) -> Option<Self> {
+        self.0.checked_add(secs).map(Self)
+    }
+
+    /// Subtracts `secs` seconds, returning `None` if the result would be before 2000-01-01
+    /// 00:00:00.
+    ///
+    /// Unlike the wrapping `Sub` implementation, this allows detecting when an adjustment would
+    /// leave the RTC's range.
+    pub fn checked_sub(self, secs: u32) -> Option<Self> {
+        self.0.checked_sub(secs).map(Self)
+    }
+
+    pub(crate) const fn new(
         year: Year,
         month: Month,
         day: Day,
@@ -87,7 +297,7 @@ impl RtcDateTimeOffset {
                 Month::November => 304,
                 Month::December => 334,
             }
-            + if year.0.get() % 4 == 0 && u8::from(month) > 2 {
+            + if year.0.get().is_multiple_of(4) && month as u8 > 2 {
                 1
             } else {
                 0
@@ -101,7 +311,34 @@ impl RtcDateTimeOffset {
     }
 }
 
+/// Calculates the RTC value corresponding to the given date and time.
+///
+/// Only dates and times within the years 2000-2099 can be represented by the RTC. Dates and times
+/// outside of this range will return [`Error::DateOutOfRange`].
+impl TryFrom<PrimitiveDateTime> for RtcDateTimeOffset {
+    type Error = Error;
+
+    fn try_from(datetime: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        validate_year(datetime.year())?;
+        // SAFETY: All of these values are guaranteed to be within their respective ranges, since
+        // the year was validated and the remaining components are valid for any date and time.
+        Ok(unsafe {
+            Self::new(
+                Year(RangedU8::new_unchecked(
+                    (datetime.year() - RTC_ORIGIN_YEAR) as u8,
+                )),
+                datetime.month(),
+                Day(RangedU8::new_unchecked(datetime.day())),
+                Hour(RangedU8::new_unchecked(datetime.hour())),
+                Minute(RangedU8::new_unchecked(datetime.minute())),
+                Second(RangedU8::new_unchecked(datetime.second())),
+            )
+        })
+    }
+}
+
 impl From<Time> for RtcDateTimeOffset {
+    #[cfg_attr(feature = "inline-more", inline)]
     fn from(time: Time) -> Self {
         Self(unsafe {
             RangedU32::new_unchecked(
@@ -112,30 +349,31 @@ impl From<Time> for RtcDateTimeOffset {
 }
 
 impl From<RtcDateTimeOffset> for Duration {
+    #[cfg_attr(feature = "inline-more", inline)]
     fn from(rtc_offset: RtcDateTimeOffset) -> Self {
         Self::seconds(rtc_offset.0.get().into())
     }
 }
 
+impl From<RtcDateTimeOffset> for core::time::Duration {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn from(rtc_offset: RtcDateTimeOffset) -> Self {
+        Self::from_secs(rtc_offset.0.get().into())
+    }
+}
+
 impl AddAssign for RtcDateTimeOffset {
+    #[cfg_attr(feature = "inline-more", inline)]
     fn add_assign(&mut self, other: Self) {
         *self = Self(self.0.checked_add(other.0.get()).unwrap_or_else(|| {
-            if self.0 > other.0 {
-                // SAFETY: Subtracting `self.0` from the max range will always work. Also, since
-                // `self.0` is larger, adding `other.0` afterwards will always be within the range.
-                unsafe {
-                    RangedU32::MAX
-                        .unchecked_sub(self.0.get())
-                        .unchecked_add(other.0.get())
-                }
-            } else {
-                // SAFETY: Subtracting `other.0` from the max range will always work. Also, since
-                // `other.0` is larger, adding `self.0` afterwards will always be within the range.
-                unsafe {
-                    RangedU32::MAX
-                        .unchecked_sub(other.0.get())
-                        .unchecked_add(self.0.get())
-                }
+            // SAFETY: Since the previous `checked_add` failed, `other` must be greater than the
+            // distance from `self` to the max range. The wrapped sum is therefore the amount
+            // `other` exceeds that distance by, minus one, which is always within the range.
+            unsafe {
+                other
+                    .0
+                    .unchecked_sub(RangedU32::<0, RTC_MAX_SECONDS>::MAX.get() - self.0.get())
+                    .unchecked_sub(1)
             }
         }))
     }
@@ -144,13 +382,14 @@ impl AddAssign for RtcDateTimeOffset {
 impl Sub for RtcDateTimeOffset {
     type Output = RtcDateTimeOffset;
 
+    #[cfg_attr(feature = "inline-more", inline)]
     fn sub(self, other: Self) -> Self::Output {
         Self(self.0.checked_sub(other.0.get()).unwrap_or_else(|| {
             // SAFETY: Since the previous `checked_sub` failed, `other` must be greater than
             // `self`. Additionally, both the difference of both values must be less than or equal
             // to the maximum value for the `RangedU32` and must also be greater than 0.
             unsafe {
-                RangedU32::<0, 3_155_759_999>::MAX
+                RangedU32::<0, RTC_MAX_SECONDS>::MAX
                     .unchecked_sub(other.0.unchecked_sub(self.0.get()).get())
                     .unchecked_add(1)
             }
@@ -159,6 +398,7 @@ impl Sub for RtcDateTimeOffset {
 }
 
 impl SubAssign for RtcDateTimeOffset {
+    #[cfg_attr(feature = "inline-more", inline)]
     fn sub_assign(&mut self, other: Self) {
         *self = *self - other;
     }
@@ -166,10 +406,7 @@ impl SubAssign for RtcDateTimeOffset {
 
 impl Debug for RtcDateTimeOffset {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let origin =
-            unsafe { Date::from_calendar_date(2000, Month::January, 1).unwrap_unchecked() }
-                .midnight();
-        let datetime = origin + Duration::seconds(self.0.get().into());
+        let datetime = rtc_origin() + Duration::seconds(self.0.get().into());
 
         formatter
             .debug_struct("RtcDateTimeOffset")
@@ -228,7 +465,7 @@ pub(crate) struct RtcTimeOffset(pub(crate) RangedU32<0, 86_399>);
 
 impl RtcTimeOffset {
     /// Create a new offset using the hour, minute, and second read from the RTC.
-    pub(crate) fn new(hour: Hour, minute: Minute, second: Second) -> RtcTimeOffset {
+    pub(crate) const fn new(hour: Hour, minute: Minute, second: Second) -> RtcTimeOffset {
         RtcTimeOffset(unsafe {
             RangedU32::new_unchecked(
                 hour.0.get() as u32 * 3600 + minute.0.get() as u32 * 60 + second.0.get() as u32,
@@ -237,14 +474,45 @@ impl RtcTimeOffset {
     }
 }
 
+impl Sub for RtcTimeOffset {
+    type Output = RtcTimeOffset;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn sub(self, other: Self) -> Self::Output {
+        Self(self.0.checked_sub(other.0.get()).unwrap_or_else(|| {
+            // SAFETY: Since the previous `checked_sub` failed, `other` must be greater than
+            // `self`. Additionally, both the difference of both values must be less than or equal
+            // to the maximum value for the `RangedU32` and must also be greater than 0.
+            unsafe {
+                RangedU32::<0, 86_399>::MAX
+                    .unchecked_sub(other.0.unchecked_sub(self.0.get()).get())
+                    .unchecked_add(1)
+            }
+        }))
+    }
+}
+
 impl From<RtcDateTimeOffset> for RtcTimeOffset {
+    #[cfg_attr(feature = "inline-more", inline)]
     fn from(rtc_offset: RtcDateTimeOffset) -> Self {
         // SAFETY: The remainder calculated here is guaranteed to be in the required range.
         RtcTimeOffset(unsafe { RangedU32::new_unchecked(rtc_offset.0.get() % 86400) })
     }
 }
 
+impl From<RtcTimeOffset> for core::time::Duration {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn from(rtc_time_offset: RtcTimeOffset) -> Self {
+        Self::from_secs(rtc_time_offset.0.get().into())
+    }
+}
+
+/// Converts the offset into a `Time`.
+///
+/// As the RTC only has a resolution of one second, the sub-second component of the returned
+/// `Time` is always zero.
 impl From<RtcTimeOffset> for Time {
+    #[cfg_attr(feature = "inline-more", inline)]
     fn from(rtc_time_offset: RtcTimeOffset) -> Self {
         Time::MIDNIGHT + Duration::seconds(rtc_time_offset.0.get().into())
     }
@@ -267,6 +535,13 @@ impl Debug for RtcTimeOffset {
 #[cfg(test)]
 mod tests {
     use super::{
+        days_in_month,
+        is_leap_day,
+        is_leap_year,
+        iso_week,
+        rtc_origin,
+        validate_date,
+        validate_year,
         Day,
         Hour,
         Minute,
@@ -274,13 +549,401 @@ mod tests {
         RtcTimeOffset,
         Second,
         Year,
+        RTC_MAX_SECONDS,
     };
-    use deranged::{
-        RangedU32,
-        RangedU8,
+    #[cfg(not(feature = "read-only"))]
+    use super::{
+        replace_day,
+        replace_month,
+        replace_year,
+    };
+    use crate::{
+        ranged::{
+            RangedU32,
+            RangedU8,
+        },
+        Error,
+    };
+    use claims::{
+        assert_err_eq,
+        assert_none,
+        assert_ok,
+        assert_ok_eq,
+        assert_some_eq,
     };
     use gba_test::test;
-    use time::Month;
+    use time::{
+        Duration,
+        Month,
+        Time,
+    };
+    use time_macros::{
+        date,
+        datetime,
+    };
+
+    #[test]
+    fn is_leap_year_divisible_by_4() {
+        assert!(is_leap_year(2004));
+    }
+
+    #[test]
+    fn is_leap_year_not_divisible_by_4() {
+        assert!(!is_leap_year(2001));
+    }
+
+    #[test]
+    fn is_leap_year_divisible_by_100() {
+        assert!(!is_leap_year(2100));
+    }
+
+    #[test]
+    fn is_leap_year_divisible_by_400() {
+        assert!(is_leap_year(2000));
+    }
+
+    #[test]
+    fn is_leap_day_divisible_by_400() {
+        assert!(is_leap_day(date!(2000 - 02 - 29)));
+    }
+
+    #[test]
+    fn is_leap_day_divisible_by_4() {
+        assert!(is_leap_day(date!(2004 - 02 - 29)));
+    }
+
+    #[test]
+    fn is_leap_day_end_of_february() {
+        assert!(!is_leap_day(date!(2001 - 02 - 28)));
+    }
+
+    #[test]
+    fn is_leap_day_after_leap_day() {
+        assert!(!is_leap_day(date!(2004 - 03 - 01)));
+    }
+
+    #[test]
+    fn iso_week_mid_year() {
+        assert_eq!(iso_week(date!(2012 - 06 - 15)), (2012, 24));
+    }
+
+    #[test]
+    fn iso_week_january_previous_year() {
+        assert_eq!(iso_week(date!(2000 - 01 - 01)), (1999, 52));
+    }
+
+    #[test]
+    fn iso_week_december_next_year() {
+        assert_eq!(iso_week(date!(2024 - 12 - 31)), (2025, 1));
+    }
+
+    #[test]
+    fn iso_week_53() {
+        assert_eq!(iso_week(date!(2004 - 12 - 31)), (2004, 53));
+    }
+
+    #[test]
+    fn days_in_month_february_leap_year() {
+        assert_eq!(days_in_month(Month::February, 2000), 29);
+    }
+
+    #[test]
+    fn days_in_month_february_non_leap_year() {
+        assert_eq!(days_in_month(Month::February, 2001), 28);
+    }
+
+    #[test]
+    fn days_in_month_april() {
+        assert_eq!(days_in_month(Month::April, 2001), 30);
+    }
+
+    #[test]
+    fn days_in_month_july() {
+        assert_eq!(days_in_month(Month::July, 2001), 31);
+    }
+
+    #[test]
+    fn validate_date_february_30() {
+        assert_err_eq!(
+            validate_date(
+                &Year(RangedU8::new_static::<1>()),
+                Month::February,
+                &Day(RangedU8::new_static::<30>())
+            ),
+            Error::InvalidDate {
+                year: 2001,
+                month: 2,
+                day: 30
+            }
+        );
+    }
+
+    #[test]
+    fn validate_date_april_31() {
+        assert_err_eq!(
+            validate_date(
+                &Year(RangedU8::new_static::<12>()),
+                Month::April,
+                &Day(RangedU8::new_static::<31>())
+            ),
+            Error::InvalidDate {
+                year: 2012,
+                month: 4,
+                day: 31
+            }
+        );
+    }
+
+    #[test]
+    fn validate_date_february_29_leap_year() {
+        assert_ok!(validate_date(
+            &Year(RangedU8::new_static::<4>()),
+            Month::February,
+            &Day(RangedU8::new_static::<29>())
+        ));
+    }
+
+    #[test]
+    fn validate_date_february_29_year_2000() {
+        assert_ok!(validate_date(
+            &Year(RangedU8::new_static::<0>()),
+            Month::February,
+            &Day(RangedU8::new_static::<29>())
+        ));
+    }
+
+    #[test]
+    fn validate_date_february_29_non_leap_year() {
+        assert_err_eq!(
+            validate_date(
+                &Year(RangedU8::new_static::<1>()),
+                Month::February,
+                &Day(RangedU8::new_static::<29>())
+            ),
+            Error::InvalidDate {
+                year: 2001,
+                month: 2,
+                day: 29
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_year_same_month_and_day() {
+        assert_ok_eq!(
+            replace_year(date!(2012 - 12 - 21), 2024),
+            date!(2024 - 12 - 21)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_year_leap_day_to_non_leap_year() {
+        assert_ok_eq!(
+            replace_year(date!(2000 - 02 - 29), 2001),
+            date!(2001 - 02 - 28)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_year_leap_day_to_leap_year() {
+        assert_ok_eq!(
+            replace_year(date!(2000 - 02 - 29), 2004),
+            date!(2004 - 02 - 29)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_year_too_low() {
+        assert_err_eq!(
+            replace_year(date!(2012 - 12 - 21), 1999),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_year_too_high() {
+        assert_err_eq!(
+            replace_year(date!(2012 - 12 - 21), 2100),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_month_same_day() {
+        assert_ok_eq!(
+            replace_month(date!(2012 - 12 - 21), 3),
+            date!(2012 - 03 - 21)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_month_clamps_day() {
+        assert_ok_eq!(
+            replace_month(date!(2013 - 01 - 31), 2),
+            date!(2013 - 02 - 28)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_month_clamps_day_leap_year() {
+        assert_ok_eq!(
+            replace_month(date!(2012 - 01 - 31), 2),
+            date!(2012 - 02 - 29)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_month_zero() {
+        assert_err_eq!(
+            replace_month(date!(2012 - 12 - 21), 0),
+            Error::InvalidMonth(0)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_month_too_high() {
+        assert_err_eq!(
+            replace_month(date!(2012 - 12 - 21), 13),
+            Error::InvalidMonth(13)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_day_valid() {
+        assert_ok_eq!(
+            replace_day(date!(2012 - 12 - 21), 31),
+            date!(2012 - 12 - 31)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_day_nonexistent() {
+        assert_err_eq!(
+            replace_day(date!(2012 - 02 - 21), 30),
+            Error::InvalidDay(30)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn replace_day_zero() {
+        assert_err_eq!(replace_day(date!(2012 - 12 - 21), 0), Error::InvalidDay(0));
+    }
+
+    #[test]
+    fn rtc_datetime_offset_try_from_min() {
+        assert_ok_eq!(
+            RtcDateTimeOffset::try_from(datetime!(2000-01-01 0:00)),
+            RtcDateTimeOffset(RangedU32::MIN)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_try_from_max() {
+        assert_ok_eq!(
+            RtcDateTimeOffset::try_from(datetime!(2099-12-31 23:59:59)),
+            RtcDateTimeOffset(RangedU32::MAX)
+        );
+    }
+
+    #[test]
+    fn rtc_origin_plus_max() {
+        assert_eq!(
+            rtc_origin() + Duration::from(RtcDateTimeOffset(RangedU32::MAX)),
+            datetime!(2099-12-31 23:59:59)
+        );
+    }
+
+    #[test]
+    fn rtc_max_seconds() {
+        assert_eq!(
+            assert_ok!(RtcDateTimeOffset::try_from(datetime!(2099-12-31 23:59:59))).get(),
+            RTC_MAX_SECONDS
+        );
+        assert_eq!(RangedU32::<0, RTC_MAX_SECONDS>::MAX.get(), RTC_MAX_SECONDS);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn rtc_datetime_offset_serde_round_trip_compact() {
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<42>());
+        let mut buffer = [0; 8];
+
+        let bytes = assert_ok!(postcard::to_slice(&rtc_offset, &mut buffer));
+
+        assert_ok_eq!(postcard::from_bytes::<RtcDateTimeOffset>(bytes), rtc_offset);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn rtc_datetime_offset_serde_round_trip_human_readable() {
+        let rtc_offset = RtcDateTimeOffset(RangedU32::MAX);
+        let mut buffer = [0; 16];
+
+        let len = assert_ok!(serde_json_core::to_slice(&rtc_offset, &mut buffer));
+
+        assert_eq!(&buffer[..len], b"3155759999");
+        assert_ok_eq!(
+            serde_json_core::from_slice::<RtcDateTimeOffset>(&buffer[..len]),
+            (rtc_offset, len)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_try_from() {
+        assert_ok_eq!(
+            RtcDateTimeOffset::try_from(datetime!(2012-12-21 5:23)),
+            RtcDateTimeOffset(RangedU32::new_static::<409_382_580>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_try_from_before_range() {
+        assert_err_eq!(
+            RtcDateTimeOffset::try_from(datetime!(1999-12-31 23:59:59)),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_try_from_after_range() {
+        assert_err_eq!(
+            RtcDateTimeOffset::try_from(datetime!(2100-01-01 0:00)),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn validate_year_min() {
+        assert_ok!(validate_year(2000));
+    }
+
+    #[test]
+    fn validate_year_max() {
+        assert_ok!(validate_year(2099));
+    }
+
+    #[test]
+    fn validate_year_too_low() {
+        assert_err_eq!(validate_year(1999), Error::DateOutOfRange);
+    }
+
+    #[test]
+    fn validate_year_too_high() {
+        assert_err_eq!(validate_year(2100), Error::DateOutOfRange);
+    }
 
     #[test]
     fn rtc_time_offset_min() {
@@ -342,6 +1005,314 @@ mod tests {
         );
     }
 
+    #[test]
+    fn time_from_rtc_time_offset() {
+        let time = Time::from(RtcTimeOffset(RangedU32::new_static::<19_380>()));
+
+        assert_eq!(time, Time::from_hms(5, 23, 0).unwrap());
+        assert_eq!(time.nanosecond(), 0);
+    }
+
+    #[test]
+    fn time_from_rtc_time_offset_max() {
+        let time = Time::from(RtcTimeOffset(RangedU32::MAX));
+
+        assert_eq!(time, Time::from_hms(23, 59, 59).unwrap());
+        assert_eq!(time.nanosecond(), 0);
+    }
+
+    #[test]
+    fn core_duration_from_rtc_time_offset_zero() {
+        assert_eq!(
+            core::time::Duration::from(RtcTimeOffset(RangedU32::MIN)),
+            core::time::Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn core_duration_from_rtc_time_offset_max() {
+        assert_eq!(
+            core::time::Duration::from(RtcTimeOffset(RangedU32::MAX)),
+            core::time::Duration::from_secs(86_399)
+        );
+    }
+
+    #[test]
+    fn core_duration_from_rtc_datetime_offset_zero() {
+        assert_eq!(
+            core::time::Duration::from(RtcDateTimeOffset(RangedU32::MIN)),
+            core::time::Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn core_duration_from_rtc_datetime_offset_max() {
+        assert_eq!(
+            core::time::Duration::from(RtcDateTimeOffset(RangedU32::MAX)),
+            core::time::Duration::from_secs(RTC_MAX_SECONDS.into())
+        );
+    }
+
+    #[test]
+    fn rtc_time_offset_sub() {
+        assert_eq!(
+            RtcTimeOffset(RangedU32::new_static::<100>())
+                - RtcTimeOffset(RangedU32::new_static::<42>()),
+            RtcTimeOffset(RangedU32::new_static::<58>())
+        );
+    }
+
+    #[test]
+    fn rtc_time_offset_sub_wraps() {
+        assert_eq!(
+            RtcTimeOffset(RangedU32::new_static::<42>())
+                - RtcTimeOffset(RangedU32::new_static::<100>()),
+            RtcTimeOffset(RangedU32::new_static::<86_342>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_add_assign() {
+        let mut rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<100>());
+        rtc_offset += RtcDateTimeOffset(RangedU32::new_static::<42>());
+
+        assert_eq!(
+            rtc_offset,
+            RtcDateTimeOffset(RangedU32::new_static::<142>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_add_assign_wraps() {
+        let mut rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<3_155_759_998>());
+        rtc_offset += RtcDateTimeOffset(RangedU32::new_static::<5>());
+
+        assert_eq!(rtc_offset, RtcDateTimeOffset(RangedU32::new_static::<3>()));
+    }
+
+    #[test]
+    fn rtc_datetime_offset_add_assign_wraps_to_zero() {
+        let mut rtc_offset = RtcDateTimeOffset(RangedU32::MAX);
+        rtc_offset += RtcDateTimeOffset(RangedU32::new_static::<1>());
+
+        assert_eq!(rtc_offset, RtcDateTimeOffset(RangedU32::MIN));
+    }
+
+    #[test]
+    fn rtc_datetime_offset_sub_assign() {
+        let mut rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<142>());
+        rtc_offset -= RtcDateTimeOffset(RangedU32::new_static::<42>());
+
+        assert_eq!(
+            rtc_offset,
+            RtcDateTimeOffset(RangedU32::new_static::<100>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_sub_assign_wraps() {
+        let mut rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<3>());
+        rtc_offset -= RtcDateTimeOffset(RangedU32::new_static::<5>());
+
+        assert_eq!(
+            rtc_offset,
+            RtcDateTimeOffset(RangedU32::new_static::<3_155_759_998>())
+        );
+    }
+
+    #[test]
+    fn rtc_time_offset_sub_equal() {
+        assert_eq!(
+            RtcTimeOffset(RangedU32::MIN) - RtcTimeOffset(RangedU32::MIN),
+            RtcTimeOffset(RangedU32::MIN)
+        );
+        assert_eq!(
+            RtcTimeOffset(RangedU32::MAX) - RtcTimeOffset(RangedU32::MAX),
+            RtcTimeOffset(RangedU32::MIN)
+        );
+    }
+
+    #[test]
+    fn rtc_time_offset_sub_min_max() {
+        assert_eq!(
+            RtcTimeOffset(RangedU32::MIN) - RtcTimeOffset(RangedU32::MAX),
+            RtcTimeOffset(RangedU32::new_static::<1>())
+        );
+    }
+
+    #[test]
+    fn rtc_time_offset_sub_max_min() {
+        assert_eq!(
+            RtcTimeOffset(RangedU32::MAX) - RtcTimeOffset(RangedU32::MIN),
+            RtcTimeOffset(RangedU32::MAX)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_add_assign_min_max() {
+        let mut rtc_offset = RtcDateTimeOffset(RangedU32::MIN);
+        rtc_offset += RtcDateTimeOffset(RangedU32::MAX);
+
+        assert_eq!(rtc_offset, RtcDateTimeOffset(RangedU32::MAX));
+    }
+
+    #[test]
+    fn rtc_datetime_offset_add_assign_max_max() {
+        let mut rtc_offset = RtcDateTimeOffset(RangedU32::MAX);
+        rtc_offset += RtcDateTimeOffset(RangedU32::MAX);
+
+        assert_eq!(
+            rtc_offset,
+            RtcDateTimeOffset(RangedU32::new_static::<3_155_759_998>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_saturating_add() {
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::new_static::<100>())
+                .saturating_add(RtcDateTimeOffset(RangedU32::new_static::<50>())),
+            RtcDateTimeOffset(RangedU32::new_static::<150>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_saturating_add_max_max() {
+        let mut wrapping = RtcDateTimeOffset(RangedU32::MAX);
+        wrapping += RtcDateTimeOffset(RangedU32::MAX);
+
+        assert_eq!(
+            wrapping,
+            RtcDateTimeOffset(RangedU32::new_static::<3_155_759_998>())
+        );
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MAX).saturating_add(RtcDateTimeOffset(RangedU32::MAX)),
+            RtcDateTimeOffset(RangedU32::MAX)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_saturating_add_past_max() {
+        let mut wrapping = RtcDateTimeOffset(RangedU32::MAX);
+        wrapping += RtcDateTimeOffset(RangedU32::new_static::<1>());
+
+        assert_eq!(wrapping, RtcDateTimeOffset(RangedU32::MIN));
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MAX)
+                .saturating_add(RtcDateTimeOffset(RangedU32::new_static::<1>())),
+            RtcDateTimeOffset(RangedU32::MAX)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_saturating_sub() {
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::new_static::<100>())
+                .saturating_sub(RtcDateTimeOffset(RangedU32::new_static::<50>())),
+            RtcDateTimeOffset(RangedU32::new_static::<50>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_saturating_sub_min_max() {
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MIN) - RtcDateTimeOffset(RangedU32::MAX),
+            RtcDateTimeOffset(RangedU32::new_static::<1>())
+        );
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MIN).saturating_sub(RtcDateTimeOffset(RangedU32::MAX)),
+            RtcDateTimeOffset(RangedU32::MIN)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_saturating_sub_past_min() {
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MIN) - RtcDateTimeOffset(RangedU32::new_static::<1>()),
+            RtcDateTimeOffset(RangedU32::MAX)
+        );
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MIN)
+                .saturating_sub(RtcDateTimeOffset(RangedU32::new_static::<1>())),
+            RtcDateTimeOffset(RangedU32::MIN)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_checked_add() {
+        assert_some_eq!(
+            RtcDateTimeOffset(RangedU32::new_static::<100>()).checked_add(50),
+            RtcDateTimeOffset(RangedU32::new_static::<150>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_checked_add_to_max() {
+        assert_some_eq!(
+            RtcDateTimeOffset(RangedU32::MIN).checked_add(RTC_MAX_SECONDS),
+            RtcDateTimeOffset(RangedU32::MAX)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_checked_add_past_max() {
+        assert_none!(RtcDateTimeOffset(RangedU32::MAX).checked_add(1));
+    }
+
+    #[test]
+    fn rtc_datetime_offset_checked_add_u32_max() {
+        assert_none!(RtcDateTimeOffset(RangedU32::MIN).checked_add(u32::MAX));
+    }
+
+    #[test]
+    fn rtc_datetime_offset_checked_sub() {
+        assert_some_eq!(
+            RtcDateTimeOffset(RangedU32::new_static::<100>()).checked_sub(50),
+            RtcDateTimeOffset(RangedU32::new_static::<50>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_checked_sub_to_min() {
+        assert_some_eq!(
+            RtcDateTimeOffset(RangedU32::MAX).checked_sub(RTC_MAX_SECONDS),
+            RtcDateTimeOffset(RangedU32::MIN)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_checked_sub_past_min() {
+        assert_none!(RtcDateTimeOffset(RangedU32::MIN).checked_sub(1));
+    }
+
+    #[test]
+    fn rtc_datetime_offset_sub_equal() {
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MIN) - RtcDateTimeOffset(RangedU32::MIN),
+            RtcDateTimeOffset(RangedU32::MIN)
+        );
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MAX) - RtcDateTimeOffset(RangedU32::MAX),
+            RtcDateTimeOffset(RangedU32::MIN)
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_sub_min_max() {
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MIN) - RtcDateTimeOffset(RangedU32::MAX),
+            RtcDateTimeOffset(RangedU32::new_static::<1>())
+        );
+    }
+
+    #[test]
+    fn rtc_datetime_offset_sub_max_min() {
+        assert_eq!(
+            RtcDateTimeOffset(RangedU32::MAX) - RtcDateTimeOffset(RangedU32::MIN),
+            RtcDateTimeOffset(RangedU32::MAX)
+        );
+    }
+
     #[test]
     fn rtc_datetime_offset_min() {
         assert_eq!(
@@ -14,15 +14,19 @@ use serde::{
     de,
     de::{
         EnumAccess,
+        MapAccess,
+        SeqAccess,
         Unexpected,
         VariantAccess,
         Visitor,
     },
+    ser::SerializeStructVariant,
     Deserialize,
     Deserializer,
     Serialize,
     Serializer,
 };
+use time::error::ComponentRange;
 
 /// Errors that may occur when interacting with the RTC.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -39,6 +43,16 @@ pub enum Error {
     InvalidBinaryCodedDecimal(u8),
     Overflow,
     NotEnabled,
+    DateOutOfRange,
+    BusStuck,
+    Busy,
+    InvalidDate { year: u16, month: u8, day: u8 },
+    InvalidWeekday(u8),
+    InvalidComponent,
+    NoDevice,
+    VerifyFailed,
+    Underflow,
+    SoftwareClock,
 }
 
 impl Display for Error {
@@ -72,43 +86,187 @@ impl Display for Error {
             }
             Self::Overflow => formatter.write_str("the stored time is too large to be represented"),
             Self::NotEnabled => formatter.write_str("the RTC GPIO port is not enabled"),
+            Self::DateOutOfRange => formatter
+                .write_str("the date is outside of the range representable by the RTC (2000-2099)"),
+            Self::BusStuck => formatter.write_str("the RTC GPIO serial data line is stuck"),
+            Self::Busy => formatter.write_str("the RTC is already in use by another transaction"),
+            Self::InvalidDate { year, month, day } => write!(
+                formatter,
+                "RTC returned a date that does not exist: {:04}-{:02}-{:02}",
+                year, month, day
+            ),
+            Self::InvalidWeekday(value) => {
+                write!(formatter, "RTC returned an invalid weekday: {}", value)
+            }
+            Self::InvalidComponent => {
+                formatter.write_str("a date or time component is outside of its valid range")
+            }
+            Self::NoDevice => formatter.write_str("no RTC responded on the enabled GPIO port"),
+            Self::VerifyFailed => {
+                formatter.write_str("the date and time read back did not match what was written")
+            }
+            Self::Underflow => {
+                formatter.write_str("the stored time is too small to be represented")
+            }
+            Self::SoftwareClock => formatter
+                .write_str("the operation requires an RTC, but the clock is a software clock"),
         }
     }
 }
 
+/// Converts an error from constructing a [`time`] type into [`Error::InvalidComponent`].
+///
+/// This allows errors from constructing dates and times, such as with [`Time::from_hms()`], to be
+/// propagated using `?` alongside errors from this crate. The name and range of the invalid
+/// component are not preserved.
+///
+/// [`Time::from_hms()`]: time::Time::from_hms()
+impl From<ComponentRange> for Error {
+    fn from(_: ComponentRange) -> Self {
+        Self::InvalidComponent
+    }
+}
+
+impl Error {
+    /// Returns whether the error may be transient.
+    ///
+    /// Transient errors are errors that may not occur again if the same operation is retried,
+    /// such as invalid values caused by noise on the GPIO bus or the GPIO port being temporarily
+    /// disabled.
+    pub const fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidStatus(_)
+                | Self::InvalidMonth(_)
+                | Self::InvalidDay(_)
+                | Self::InvalidHour(_)
+                | Self::InvalidMinute(_)
+                | Self::InvalidSecond(_)
+                | Self::InvalidBinaryCodedDecimal(_)
+                | Self::NotEnabled
+                | Self::InvalidDate { .. }
+                | Self::InvalidWeekday(_)
+        )
+    }
+
+    /// Returns a stable numeric code identifying the variant.
+    ///
+    /// These codes are the same discriminants used when serializing with `serde`, and are
+    /// therefore guaranteed not to change. Any contained values are not included in the code.
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::PowerFailure => 0,
+            Self::TestMode => 1,
+            Self::AmPmBitPresent => 2,
+            Self::InvalidStatus(_) => 3,
+            Self::InvalidMonth(_) => 4,
+            Self::InvalidDay(_) => 5,
+            Self::InvalidHour(_) => 6,
+            Self::InvalidMinute(_) => 7,
+            Self::InvalidSecond(_) => 8,
+            Self::InvalidBinaryCodedDecimal(_) => 9,
+            Self::Overflow => 10,
+            Self::NotEnabled => 11,
+            Self::DateOutOfRange => 12,
+            Self::BusStuck => 13,
+            Self::Busy => 14,
+            Self::InvalidDate { .. } => 15,
+            Self::InvalidWeekday(_) => 16,
+            Self::InvalidComponent => 17,
+            Self::NoDevice => 18,
+            Self::VerifyFailed => 19,
+            Self::Underflow => 20,
+            Self::SoftwareClock => 21,
+        }
+    }
+
+    /// Returns the variant identified by the given numeric code.
+    ///
+    /// Only variants without contained values can be recovered from their code. `None` is
+    /// returned for codes of variants containing values, as well as for unknown codes.
+    pub const fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(Self::PowerFailure),
+            1 => Some(Self::TestMode),
+            2 => Some(Self::AmPmBitPresent),
+            10 => Some(Self::Overflow),
+            11 => Some(Self::NotEnabled),
+            12 => Some(Self::DateOutOfRange),
+            13 => Some(Self::BusStuck),
+            14 => Some(Self::Busy),
+            17 => Some(Self::InvalidComponent),
+            18 => Some(Self::NoDevice),
+            19 => Some(Self::VerifyFailed),
+            20 => Some(Self::Underflow),
+            21 => Some(Self::SoftwareClock),
+            _ => None,
+        }
+    }
+}
+
+/// The names of each variant, indexed by their [`Error::code()`].
+#[cfg(feature = "serde")]
+const VARIANTS: &[&str] = &[
+    "PowerFailure",
+    "TestMode",
+    "AmPmBitPresent",
+    "InvalidStatus",
+    "InvalidMonth",
+    "InvalidDay",
+    "InvalidHour",
+    "InvalidMinute",
+    "InvalidSecond",
+    "InvalidBinaryCodedDecimal",
+    "Overflow",
+    "NotEnabled",
+    "DateOutOfRange",
+    "BusStuck",
+    "Busy",
+    "InvalidDate",
+    "InvalidWeekday",
+    "InvalidComponent",
+    "NoDevice",
+    "VerifyFailed",
+    "Underflow",
+    "SoftwareClock",
+];
+
+/// The fields of the `Error::InvalidDate` variant.
+#[cfg(feature = "serde")]
+const INVALID_DATE_FIELDS: &[&str] = &["year", "month", "day"];
+
 #[cfg(feature = "serde")]
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
+        let index = self.code();
+        let name = VARIANTS[index as usize];
         match self {
-            Self::PowerFailure => serializer.serialize_unit_variant("Error", 0, "PowerFailure"),
-            Self::TestMode => serializer.serialize_unit_variant("Error", 1, "TestMode"),
-            Self::AmPmBitPresent => serializer.serialize_unit_variant("Error", 2, "AmPmBitPresent"),
-            Self::InvalidStatus(value) => {
-                serializer.serialize_newtype_variant("Error", 3, "InvalidStatus", value)
-            }
-            Self::InvalidMonth(value) => {
-                serializer.serialize_newtype_variant("Error", 4, "InvalidMonth", value)
-            }
-            Self::InvalidDay(value) => {
-                serializer.serialize_newtype_variant("Error", 5, "InvalidDay", value)
-            }
-            Self::InvalidHour(value) => {
-                serializer.serialize_newtype_variant("Error", 6, "InvalidHour", value)
+            Self::InvalidStatus(value)
+            | Self::InvalidMonth(value)
+            | Self::InvalidDay(value)
+            | Self::InvalidHour(value)
+            | Self::InvalidMinute(value)
+            | Self::InvalidSecond(value)
+            | Self::InvalidBinaryCodedDecimal(value)
+            | Self::InvalidWeekday(value) => {
+                serializer.serialize_newtype_variant("Error", index.into(), name, value)
             }
-            Self::InvalidMinute(value) => {
-                serializer.serialize_newtype_variant("Error", 7, "InvalidMinute", value)
-            }
-            Self::InvalidSecond(value) => {
-                serializer.serialize_newtype_variant("Error", 8, "InvalidSecond", value)
-            }
-            Self::InvalidBinaryCodedDecimal(value) => {
-                serializer.serialize_newtype_variant("Error", 9, "InvalidBinaryCodedDecimal", value)
+            Self::InvalidDate { year, month, day } => {
+                let mut r#struct = serializer.serialize_struct_variant(
+                    "Error",
+                    index.into(),
+                    name,
+                    INVALID_DATE_FIELDS.len(),
+                )?;
+                r#struct.serialize_field("year", year)?;
+                r#struct.serialize_field("month", month)?;
+                r#struct.serialize_field("day", day)?;
+                r#struct.end()
             }
-            Self::Overflow => serializer.serialize_unit_variant("Error", 10, "Overflow"),
-            Self::NotEnabled => serializer.serialize_unit_variant("Error", 11, "NotEnabled"),
+            _ => serializer.serialize_unit_variant("Error", index.into(), name),
         }
     }
 }
@@ -132,6 +290,16 @@ impl<'de> Deserialize<'de> for Error {
             InvalidBinaryCodedDecimal,
             Overflow,
             NotEnabled,
+            DateOutOfRange,
+            BusStuck,
+            Busy,
+            InvalidDate,
+            InvalidWeekday,
+            InvalidComponent,
+            NoDevice,
+            VerifyFailed,
+            Underflow,
+            SoftwareClock,
         }
 
         impl<'de> Deserialize<'de> for Variant {
@@ -145,27 +313,22 @@ impl<'de> Deserialize<'de> for Error {
                     type Value = Variant;
 
                     fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
-                        formatter.write_str("`PowerFailure`, `TestMode`, `AmPmBitPresent`, `InvalidStatus`, `InvalidMonth`, `InvalidDay`, `InvalidHour`, `InvalidMinute`, `InvalidSecond`, `InvalidBinaryCodedDecimal`, `Overflow`, or `NotEnabled`")
+                        formatter.write_str("`PowerFailure`, `TestMode`, `AmPmBitPresent`, `InvalidStatus`, `InvalidMonth`, `InvalidDay`, `InvalidHour`, `InvalidMinute`, `InvalidSecond`, `InvalidBinaryCodedDecimal`, `Overflow`, `NotEnabled`, `DateOutOfRange`, `BusStuck`, `Busy`, `InvalidDate`, `InvalidWeekday`, `InvalidComponent`, `NoDevice`, `VerifyFailed`, `Underflow`, or `SoftwareClock`")
                     }
 
                     fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
                     where
                         E: de::Error,
                     {
-                        match value {
-                            0 => Ok(Variant::PowerFailure),
-                            1 => Ok(Variant::TestMode),
-                            2 => Ok(Variant::AmPmBitPresent),
-                            3 => Ok(Variant::InvalidStatus),
-                            4 => Ok(Variant::InvalidMonth),
-                            5 => Ok(Variant::InvalidDay),
-                            6 => Ok(Variant::InvalidHour),
-                            7 => Ok(Variant::InvalidMinute),
-                            8 => Ok(Variant::InvalidSecond),
-                            9 => Ok(Variant::InvalidBinaryCodedDecimal),
-                            10 => Ok(Variant::Overflow),
-                            11 => Ok(Variant::NotEnabled),
-                            _ => Err(de::Error::invalid_value(Unexpected::Unsigned(value), &self)),
+                        // Variant indices are the same as the error codes.
+                        match usize::try_from(value)
+                            .ok()
+                            .and_then(|index| VARIANTS.get(index))
+                        {
+                            Some(name) => self.visit_str(name),
+                            None => {
+                                Err(de::Error::invalid_value(Unexpected::Unsigned(value), &self))
+                            }
                         }
                     }
 
@@ -186,6 +349,16 @@ impl<'de> Deserialize<'de> for Error {
                             "InvalidBinaryCodedDecimal" => Ok(Variant::InvalidBinaryCodedDecimal),
                             "Overflow" => Ok(Variant::Overflow),
                             "NotEnabled" => Ok(Variant::NotEnabled),
+                            "DateOutOfRange" => Ok(Variant::DateOutOfRange),
+                            "BusStuck" => Ok(Variant::BusStuck),
+                            "Busy" => Ok(Variant::Busy),
+                            "InvalidDate" => Ok(Variant::InvalidDate),
+                            "InvalidWeekday" => Ok(Variant::InvalidWeekday),
+                            "InvalidComponent" => Ok(Variant::InvalidComponent),
+                            "NoDevice" => Ok(Variant::NoDevice),
+                            "VerifyFailed" => Ok(Variant::VerifyFailed),
+                            "Underflow" => Ok(Variant::Underflow),
+                            "SoftwareClock" => Ok(Variant::SoftwareClock),
                             _ => Err(de::Error::unknown_variant(value, VARIANTS)),
                         }
                     }
@@ -207,6 +380,16 @@ impl<'de> Deserialize<'de> for Error {
                             b"InvalidBinaryCodedDecimal" => Ok(Variant::InvalidBinaryCodedDecimal),
                             b"Overflow" => Ok(Variant::Overflow),
                             b"NotEnabled" => Ok(Variant::NotEnabled),
+                            b"DateOutOfRange" => Ok(Variant::DateOutOfRange),
+                            b"BusStuck" => Ok(Variant::BusStuck),
+                            b"Busy" => Ok(Variant::Busy),
+                            b"InvalidDate" => Ok(Variant::InvalidDate),
+                            b"InvalidWeekday" => Ok(Variant::InvalidWeekday),
+                            b"InvalidComponent" => Ok(Variant::InvalidComponent),
+                            b"NoDevice" => Ok(Variant::NoDevice),
+                            b"VerifyFailed" => Ok(Variant::VerifyFailed),
+                            b"Underflow" => Ok(Variant::Underflow),
+                            b"SoftwareClock" => Ok(Variant::SoftwareClock),
                             _ => {
                                 let utf8_value =
                                     str::from_utf8(value).unwrap_or("\u{fffd}\u{fffd}\u{fffd}");
@@ -220,6 +403,135 @@ impl<'de> Deserialize<'de> for Error {
             }
         }
 
+        enum InvalidDateField {
+            Year,
+            Month,
+            Day,
+        }
+
+        impl<'de> Deserialize<'de> for InvalidDateField {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct InvalidDateFieldVisitor;
+
+                impl<'de> Visitor<'de> for InvalidDateFieldVisitor {
+                    type Value = InvalidDateField;
+
+                    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                        formatter.write_str("`year`, `month`, or `day`")
+                    }
+
+                    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            0 => Ok(InvalidDateField::Year),
+                            1 => Ok(InvalidDateField::Month),
+                            2 => Ok(InvalidDateField::Day),
+                            _ => Err(de::Error::invalid_value(Unexpected::Unsigned(value), &self)),
+                        }
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            "year" => Ok(InvalidDateField::Year),
+                            "month" => Ok(InvalidDateField::Month),
+                            "day" => Ok(InvalidDateField::Day),
+                            _ => Err(de::Error::unknown_field(value, INVALID_DATE_FIELDS)),
+                        }
+                    }
+
+                    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            b"year" => Ok(InvalidDateField::Year),
+                            b"month" => Ok(InvalidDateField::Month),
+                            b"day" => Ok(InvalidDateField::Day),
+                            _ => {
+                                let utf8_value =
+                                    str::from_utf8(value).unwrap_or("\u{fffd}\u{fffd}\u{fffd}");
+                                Err(de::Error::unknown_field(utf8_value, INVALID_DATE_FIELDS))
+                            }
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(InvalidDateFieldVisitor)
+            }
+        }
+
+        struct InvalidDateVisitor;
+
+        impl<'de> Visitor<'de> for InvalidDateVisitor {
+            type Value = Error;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("struct variant Error::InvalidDate")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let year = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let month = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let day = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok(Error::InvalidDate { year, month, day })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut year = None;
+                let mut month = None;
+                let mut day = None;
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        InvalidDateField::Year => {
+                            if year.is_some() {
+                                return Err(de::Error::duplicate_field("year"));
+                            }
+                            year = Some(map.next_value()?);
+                        }
+                        InvalidDateField::Month => {
+                            if month.is_some() {
+                                return Err(de::Error::duplicate_field("month"));
+                            }
+                            month = Some(map.next_value()?);
+                        }
+                        InvalidDateField::Day => {
+                            if day.is_some() {
+                                return Err(de::Error::duplicate_field("day"));
+                            }
+                            day = Some(map.next_value()?);
+                        }
+                    }
+                }
+
+                Ok(Error::InvalidDate {
+                    year: year.ok_or_else(|| de::Error::missing_field("year"))?,
+                    month: month.ok_or_else(|| de::Error::missing_field("month"))?,
+                    day: day.ok_or_else(|| de::Error::missing_field("day"))?,
+                })
+            }
+        }
+
         struct ErrorVisitor;
 
         impl<'de> Visitor<'de> for ErrorVisitor {
@@ -265,24 +577,232 @@ impl<'de> Deserialize<'de> for Error {
                         access.unit_variant()?;
                         Error::NotEnabled
                     }
+                    Variant::DateOutOfRange => {
+                        access.unit_variant()?;
+                        Error::DateOutOfRange
+                    }
+                    Variant::BusStuck => {
+                        access.unit_variant()?;
+                        Error::BusStuck
+                    }
+                    Variant::Busy => {
+                        access.unit_variant()?;
+                        Error::Busy
+                    }
+                    Variant::InvalidDate => {
+                        access.struct_variant(INVALID_DATE_FIELDS, InvalidDateVisitor)?
+                    }
+                    Variant::InvalidWeekday => Error::InvalidWeekday(access.newtype_variant()?),
+                    Variant::InvalidComponent => {
+                        access.unit_variant()?;
+                        Error::InvalidComponent
+                    }
+                    Variant::NoDevice => {
+                        access.unit_variant()?;
+                        Error::NoDevice
+                    }
+                    Variant::VerifyFailed => {
+                        access.unit_variant()?;
+                        Error::VerifyFailed
+                    }
+                    Variant::Underflow => {
+                        access.unit_variant()?;
+                        Error::Underflow
+                    }
+                    Variant::SoftwareClock => {
+                        access.unit_variant()?;
+                        Error::SoftwareClock
+                    }
                 })
             }
         }
 
-        const VARIANTS: &[&str] = &[
-            "PowerFailure",
-            "TestMode",
-            "AmPmBitPresent",
-            "InvalidStatus",
-            "InvalidMonth",
-            "InvalidDay",
-            "InvalidHour",
-            "InvalidMinute",
-            "InvalidSecond",
-            "InvalidBinaryCodedDecimal",
-            "Overflow",
-            "NotEnabled",
-        ];
         deserializer.deserialize_enum("Error", VARIANTS, ErrorVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use claims::{
+        assert_err_eq,
+        assert_none,
+        assert_some_eq,
+    };
+    #[cfg(feature = "serde")]
+    use claims::{
+        assert_ok,
+        assert_ok_eq,
+    };
+    use gba_test::test;
+    use time::Time;
+
+    #[test]
+    fn is_transient() {
+        assert!(Error::InvalidBinaryCodedDecimal(0xff).is_transient());
+        assert!(Error::NotEnabled.is_transient());
+        assert!(Error::InvalidDate {
+            year: 2001,
+            month: 2,
+            day: 30
+        }
+        .is_transient());
+        assert!(Error::InvalidWeekday(9).is_transient());
+    }
+
+    #[test]
+    fn is_not_transient() {
+        assert!(!Error::PowerFailure.is_transient());
+        assert!(!Error::Overflow.is_transient());
+        assert!(!Error::Busy.is_transient());
+        assert!(!Error::InvalidComponent.is_transient());
+        assert!(!Error::NoDevice.is_transient());
+        assert!(!Error::VerifyFailed.is_transient());
+        assert!(!Error::Underflow.is_transient());
+        assert!(!Error::SoftwareClock.is_transient());
+    }
+
+    #[test]
+    fn code() {
+        assert_eq!(Error::PowerFailure.code(), 0);
+        assert_eq!(Error::TestMode.code(), 1);
+        assert_eq!(Error::AmPmBitPresent.code(), 2);
+        assert_eq!(Error::InvalidStatus(0).code(), 3);
+        assert_eq!(Error::InvalidMonth(0).code(), 4);
+        assert_eq!(Error::InvalidDay(0).code(), 5);
+        assert_eq!(Error::InvalidHour(0).code(), 6);
+        assert_eq!(Error::InvalidMinute(0).code(), 7);
+        assert_eq!(Error::InvalidSecond(0).code(), 8);
+        assert_eq!(Error::InvalidBinaryCodedDecimal(0).code(), 9);
+        assert_eq!(Error::Overflow.code(), 10);
+        assert_eq!(Error::NotEnabled.code(), 11);
+        assert_eq!(Error::DateOutOfRange.code(), 12);
+        assert_eq!(Error::BusStuck.code(), 13);
+        assert_eq!(Error::Busy.code(), 14);
+        assert_eq!(
+            Error::InvalidDate {
+                year: 2001,
+                month: 2,
+                day: 30
+            }
+            .code(),
+            15
+        );
+        assert_eq!(Error::InvalidWeekday(0).code(), 16);
+        assert_eq!(Error::InvalidComponent.code(), 17);
+        assert_eq!(Error::NoDevice.code(), 18);
+        assert_eq!(Error::VerifyFailed.code(), 19);
+        assert_eq!(Error::Underflow.code(), 20);
+        assert_eq!(Error::SoftwareClock.code(), 21);
+    }
+
+    #[test]
+    fn code_round_trip_unit_variants() {
+        for error in [
+            Error::PowerFailure,
+            Error::TestMode,
+            Error::AmPmBitPresent,
+            Error::Overflow,
+            Error::NotEnabled,
+            Error::DateOutOfRange,
+            Error::BusStuck,
+            Error::Busy,
+            Error::InvalidComponent,
+            Error::NoDevice,
+            Error::VerifyFailed,
+            Error::Underflow,
+            Error::SoftwareClock,
+        ] {
+            assert_some_eq!(Error::from_code(error.code()), error);
+        }
+    }
+
+    #[test]
+    fn from_code_value_variant() {
+        assert_none!(Error::from_code(Error::InvalidMonth(13).code()));
+    }
+
+    #[test]
+    fn from_code_struct_variant() {
+        assert_none!(Error::from_code(
+            Error::InvalidDate {
+                year: 2001,
+                month: 2,
+                day: 30
+            }
+            .code()
+        ));
+    }
+
+    #[test]
+    fn from_component_range() {
+        fn from_hms() -> Result<Time, Error> {
+            Ok(Time::from_hms(24, 0, 0)?)
+        }
+
+        assert_err_eq!(from_hms(), Error::InvalidComponent);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_invalid_weekday() {
+        let mut buffer = [0; 8];
+
+        let bytes = assert_ok!(postcard::to_slice(&Error::InvalidWeekday(9), &mut buffer));
+
+        assert_eq!(bytes, [16, 9]);
+        assert_ok_eq!(
+            postcard::from_bytes::<Error>(bytes),
+            Error::InvalidWeekday(9)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_no_device() {
+        let mut buffer = [0; 8];
+
+        let bytes = assert_ok!(postcard::to_slice(&Error::NoDevice, &mut buffer));
+
+        assert_eq!(bytes, [18]);
+        assert_ok_eq!(postcard::from_bytes::<Error>(bytes), Error::NoDevice);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_verify_failed() {
+        let mut buffer = [0; 8];
+
+        let bytes = assert_ok!(postcard::to_slice(&Error::VerifyFailed, &mut buffer));
+
+        assert_eq!(bytes, [19]);
+        assert_ok_eq!(postcard::from_bytes::<Error>(bytes), Error::VerifyFailed);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_underflow() {
+        let mut buffer = [0; 8];
+
+        let bytes = assert_ok!(postcard::to_slice(&Error::Underflow, &mut buffer));
+
+        assert_eq!(bytes, [20]);
+        assert_ok_eq!(postcard::from_bytes::<Error>(bytes), Error::Underflow);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_software_clock() {
+        let mut buffer = [0; 8];
+
+        let bytes = assert_ok!(postcard::to_slice(&Error::SoftwareClock, &mut buffer));
+
+        assert_eq!(bytes, [21]);
+        assert_ok_eq!(postcard::from_bytes::<Error>(bytes), Error::SoftwareClock);
+    }
+
+    #[test]
+    fn from_code_unknown() {
+        assert_none!(Error::from_code(0xff));
+    }
+}
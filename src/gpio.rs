@@ -1,37 +1,247 @@
 //! Communications with the RTC over General Purpose I/O.
 
+#[cfg(feature = "host-stub")]
+mod host_stub;
+
 use crate::{
-    bcd::Bcd,
+    bcd::{
+        decode_hour,
+        seconds_byte_is_test,
+        Bcd,
+    },
     date_time::{
+        validate_date,
+        Day,
+        Hour,
+        HourMode,
+        Minute,
         RtcDateTimeOffset,
         RtcTimeOffset,
+        Second,
+        Year,
     },
+    ranged::RangedU8,
     Error,
 };
-use core::ops::{
-    BitAnd,
-    BitOr,
+use core::{
+    ops::{
+        BitAnd,
+        BitOr,
+    },
+    ptr::addr_of_mut,
+    slice,
 };
+use time::Month;
 
 /// I/O Port Data.
 ///
 /// Used for sending data directly to the RTC chip.
-const DATA: *mut Data = 0x080000c4 as *mut Data;
+#[cfg(not(feature = "host-stub"))]
+const DATA: *mut u16 = 0x080000c4 as *mut u16;
+#[cfg(feature = "host-stub")]
+const DATA: *mut u16 = addr_of_mut!(host_stub::DATA);
 
 /// I/O Port Direction.
 ///
 /// This specifies which bits are writable and which bits are readable.
-const RW_MODE: *mut RwMode = 0x080000c6 as *mut RwMode;
+#[cfg(not(feature = "host-stub"))]
+const RW_MODE: *mut u16 = 0x080000c6 as *mut u16;
+#[cfg(feature = "host-stub")]
+const RW_MODE: *mut u16 = addr_of_mut!(host_stub::RW_MODE);
 
 /// I/O Port Control.
 ///
 /// By setting this to `1`, the General Purpose I/O (GPIO) will be both readable and writable.
+#[cfg(not(feature = "host-stub"))]
 const ENABLE: *mut u16 = 0x080000c8 as *mut u16;
+#[cfg(feature = "host-stub")]
+const ENABLE: *mut u16 = addr_of_mut!(host_stub::ENABLE);
 
 /// Interrupt Master Enable.
 ///
 /// This register allows enabling and disabling interrupts.
-const IME: *mut bool = 0x0400_0208 as *mut bool;
+#[cfg(not(feature = "host-stub"))]
+const IME: *mut u16 = 0x0400_0208 as *mut u16;
+#[cfg(feature = "host-stub")]
+const IME: *mut u16 = addr_of_mut!(host_stub::IME);
+
+/// DMA Control, for each of the four DMA channels.
+///
+/// Bit 15 of each register enables its channel.
+#[cfg(not(feature = "host-stub"))]
+const DMA_CONTROL: [*mut u16; 4] = [
+    0x0400_00ba as *mut u16,
+    0x0400_00c6 as *mut u16,
+    0x0400_00d2 as *mut u16,
+    0x0400_00de as *mut u16,
+];
+#[cfg(feature = "host-stub")]
+const DMA_CONTROL: [*mut u16; 4] = [
+    addr_of_mut!(host_stub::DMA_CONTROL).cast::<u16>(),
+    addr_of_mut!(host_stub::DMA_CONTROL)
+        .cast::<u16>()
+        .wrapping_add(1),
+    addr_of_mut!(host_stub::DMA_CONTROL)
+        .cast::<u16>()
+        .wrapping_add(2),
+    addr_of_mut!(host_stub::DMA_CONTROL)
+        .cast::<u16>()
+        .wrapping_add(3),
+];
+
+/// The bit within a DMA Control register that enables its channel.
+const DMA_ENABLE: u16 = 0b1000_0000_0000_0000;
+
+/// The number of bytes returned by the RTC when reading the date and time.
+///
+/// This is the year, month, day, weekday, hour, minute, and second, in that order.
+pub(crate) const DATETIME_BUFFER_LEN: usize = 7;
+
+/// Log an interaction with the RTC at the trace level.
+///
+/// This only logs if the `trace` feature is enabled. Otherwise, it expands to nothing.
+///
+/// Logging is performed per byte, never per bit, as logging between bits would disrupt the serial
+/// clock's timing. Note that logging still lengthens each transaction, during which interrupts are
+/// disabled.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "trace")]
+        log::trace!($($arg)*);
+    };
+}
+
+/// Whether a transaction with the RTC is currently in progress.
+///
+/// The GBA has a single core, so this only needs to guard against an interrupt handler starting a
+/// transaction while another is in progress. A plain flag accessed with volatile reads and writes
+/// is therefore sufficient.
+static mut BUSY: bool = false;
+
+/// Whether DMA is paused for the duration of each transaction.
+///
+/// This is shared by all transactions, and is only changed by
+/// [`hw::set_pause_dma()`](crate::hw::set_pause_dma()).
+static mut PAUSE_DMA: bool = false;
+
+/// Returns whether DMA is currently paused during transactions.
+#[cfg_attr(feature = "inline-more", inline)]
+pub(crate) fn pause_dma() -> bool {
+    unsafe { addr_of_mut!(PAUSE_DMA).read_volatile() }
+}
+
+/// Sets whether DMA is paused during all subsequent transactions.
+pub(crate) fn set_pause_dma(pause_dma: bool) {
+    unsafe { addr_of_mut!(PAUSE_DMA).write_volatile(pause_dma) }
+}
+
+/// Disables every enabled DMA channel among `controls`.
+///
+/// Returns the previous value of each control register, to be restored by
+/// `resume_dma_channels()`.
+///
+/// # Safety
+/// Every pointer in `controls` must be valid for volatile reads and writes.
+unsafe fn pause_dma_channels(controls: &[*mut u16; 4]) -> [u16; 4] {
+    controls.map(|control| {
+        let value = unsafe { control.read_volatile() };
+        if value & DMA_ENABLE != 0 {
+            unsafe { control.write_volatile(value & !DMA_ENABLE) };
+        }
+        value
+    })
+}
+
+/// Restores each DMA channel among `controls` that was enabled in `previous`.
+///
+/// Channels that were not enabled are left untouched.
+///
+/// # Safety
+/// Every pointer in `controls` must be valid for volatile writes.
+unsafe fn resume_dma_channels(controls: &[*mut u16; 4], previous: [u16; 4]) {
+    for (control, value) in controls.iter().zip(previous) {
+        if value & DMA_ENABLE != 0 {
+            unsafe { control.write_volatile(value) };
+        }
+    }
+}
+
+/// Rearranges the bits of `byte` from the standard order into the order they are transferred in.
+///
+/// Data bytes are transferred starting with their lowest bit, and command bytes starting with
+/// their highest bit, as required by the S-3511A. If the `msb-first` feature is enabled, the bits
+/// of every byte are reversed instead. Otherwise, this returns `byte` unchanged and compiles away
+/// entirely.
+///
+/// This is its own inverse, so it is used both before sending and after receiving a byte.
+#[inline(always)]
+fn order_bits(byte: u8) -> u8 {
+    if cfg!(feature = "msb-first") {
+        byte.reverse_bits()
+    } else {
+        byte
+    }
+}
+
+/// An in-progress transaction with the RTC.
+///
+/// While a `Transaction` exists, no other transaction may begin and interrupts are disabled.
+/// Dropping the `Transaction` restores the previous interrupt enable value and allows new
+/// transactions to begin. This happens on every exit path, including when an error is returned.
+#[derive(Debug)]
+struct Transaction {
+    /// The interrupt enable value prior to the transaction.
+    previous_ime: bool,
+    /// The DMA control values prior to the transaction, if DMA was paused.
+    previous_dma: Option<[u16; 4]>,
+}
+
+impl Transaction {
+    /// Begin a new transaction.
+    ///
+    /// Returns [`Error::Busy`] if another transaction is already in progress, or
+    /// [`Error::NotEnabled`] if GPIO is not enabled.
+    fn begin() -> Result<Self, Error> {
+        if unsafe { addr_of_mut!(BUSY).read_volatile() } {
+            return Err(Error::Busy);
+        }
+        unsafe { addr_of_mut!(BUSY).write_volatile(true) };
+
+        // Disable interrupts, storing the previous value.
+        //
+        // This prevents interrupts while reading data from the device. This is necessary because
+        // GPIO reads data one bit at a time.
+        let previous_ime = Volatile.read(Register::Ime) != 0;
+        Volatile.write(Register::Ime, 0);
+        // Pause DMA if configured to do so, storing the previous values.
+        let previous_dma = pause_dma().then(|| unsafe { pause_dma_channels(&DMA_CONTROL) });
+        let transaction = Self {
+            previous_ime,
+            previous_dma,
+        };
+        trace!("RTC transaction begin: IME={}", previous_ime);
+
+        // Check if enabled.
+        if !is_enabled() {
+            return Err(Error::NotEnabled);
+        }
+
+        Ok(transaction)
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        // Resume any paused DMA channels.
+        if let Some(previous_dma) = self.previous_dma {
+            unsafe { resume_dma_channels(&DMA_CONTROL, previous_dma) };
+        }
+        // Restore the previous interrupt enable value.
+        Volatile.write(Register::Ime, self.previous_ime.into());
+        unsafe { addr_of_mut!(BUSY).write_volatile(false) };
+        trace!("RTC transaction end: IME={}", self.previous_ime);
+    }
+}
 
 /// A command used to interact with the RTC.
 ///
@@ -72,6 +282,8 @@ impl Data {
     /// Serial Clock Input.
     const SCK: Data = Data(0b0000_0000_0000_0001);
     /// Serial Data Input/Output.
+    // The stubbed RTC never samples the data line.
+    #[cfg_attr(feature = "host-stub", allow(dead_code))]
     const SIO: Data = Data(0b0000_0000_0000_0010);
     /// Chip Select.
     const CS: Data = Data(0b0000_0000_0000_0100);
@@ -115,69 +327,295 @@ impl From<Data> for u8 {
     }
 }
 
+/// A register used to communicate with the RTC.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Register {
+    /// `DATA`.
+    Data,
+    /// `RW_MODE`.
+    RwMode,
+    /// `ENABLE`.
+    Enable,
+    /// `IME`.
+    Ime,
+}
+
+impl Register {
+    /// Returns the address of this register.
+    fn address(self) -> *mut u16 {
+        match self {
+            Self::Data => DATA,
+            Self::RwMode => RW_MODE,
+            Self::Enable => ENABLE,
+            Self::Ime => IME,
+        }
+    }
+}
+
+/// Access to the registers used to communicate with the RTC.
+///
+/// Every access of `DATA`, `RW_MODE`, `ENABLE`, and `IME` is made through this trait. Outside of
+/// tests, this is always `Volatile`, which accesses the registers themselves. Tests can instead
+/// provide an implementation that records the values written, allowing the bits sent to the RTC
+/// to be checked without any hardware.
+trait RegisterAccess {
+    /// Read the value of `register`.
+    fn read(&mut self, register: Register) -> u16;
+
+    /// Write `value` to `register`.
+    fn write(&mut self, register: Register, value: u16);
+
+    /// Read the value of `DATA`.
+    // The stubbed RTC never samples the data line.
+    #[cfg_attr(feature = "host-stub", allow(dead_code))]
+    #[inline(always)]
+    fn read_data(&mut self) -> Data {
+        Data(self.read(Register::Data))
+    }
+
+    /// Write `data` to `DATA`.
+    #[inline(always)]
+    fn write_data(&mut self, data: Data) {
+        self.write(Register::Data, data.0);
+    }
+
+    /// Write `rw_mode` to `RW_MODE`.
+    #[inline(always)]
+    fn write_rw_mode(&mut self, rw_mode: RwMode) {
+        self.write(Register::RwMode, rw_mode as u16);
+    }
+}
+
+/// Volatile access to the registers themselves.
+#[derive(Debug)]
+struct Volatile;
+
+impl RegisterAccess for Volatile {
+    #[inline(always)]
+    fn read(&mut self, register: Register) -> u16 {
+        unsafe { register.address().read_volatile() }
+    }
+
+    #[inline(always)]
+    fn write(&mut self, register: Register, value: u16) {
+        unsafe { register.address().write_volatile(value) }
+    }
+}
+
 /// Send a command to the RTC.
 ///
 /// This must be called before every interaction with the RTC. See the `Command` variants for more
 /// information.
-fn send_command(command: Command) {
-    let bits = (command as u8) << 1;
+#[cfg_attr(feature = "inline-more", inline)]
+fn send_command(registers: &mut impl RegisterAccess, command: Command) {
+    send_command_byte(registers, command as u8);
+}
+
+/// Send a raw command byte to the RTC.
+///
+/// Unlike `send_command()`, this allows sending values that are not defined as a `Command`.
+#[cfg(not(feature = "host-stub"))]
+fn send_command_byte(registers: &mut impl RegisterAccess, command: u8) {
+    trace!("RTC command: {:#04x}", command);
+    let bits = order_bits(command) << 1;
     // Bits must be sent from highest to lowest.
     for i in (0..8).rev() {
         let bit = (bits >> i) & 2;
-        unsafe {
-            DATA.write_volatile(Data::CS | bit);
-            DATA.write_volatile(Data::CS | bit);
-            DATA.write_volatile(Data::CS | bit);
-            DATA.write_volatile(Data::CS | Data::SCK | bit);
-        }
+        registers.write_data(Data::CS | bit);
+        registers.write_data(Data::CS | bit);
+        registers.write_data(Data::CS | bit);
+        registers.write_data(Data::CS | Data::SCK | bit);
     }
 }
 
-/// Read a single byte.
-fn read_byte() -> u8 {
-    let mut byte: u8 = 0;
-    for _ in 0..8 {
-        unsafe {
-            DATA.write_volatile(Data::CS);
-            DATA.write_volatile(Data::CS);
-            DATA.write_volatile(Data::CS);
-            DATA.write_volatile(Data::CS);
-            DATA.write_volatile(Data::CS);
-            DATA.write_volatile(Data::CS | Data::SCK);
-            byte = (byte >> 1) | (((u8::from(DATA.read_volatile() & Data::SIO)) >> 1) << 7);
-        }
+/// Send a raw command byte to the stubbed RTC.
+///
+/// Bits are still reordered according to the `msb-first` feature, just as they would be when sent
+/// to the hardware. The stubbed RTC receives whole bytes, so `registers` is not used.
+#[cfg(feature = "host-stub")]
+fn send_command_byte(_registers: &mut impl RegisterAccess, command: u8) {
+    trace!("RTC command: {:#04x}", command);
+    host_stub::send_command(order_bits(command));
+}
+
+/// Read a single bit.
+///
+/// The repeated writes to `DATA` are not redundant: they hold the serial clock low for long enough
+/// to satisfy the S-3511A's timing requirements before it is raised and the bit is sampled.
+#[cfg(not(feature = "host-stub"))]
+#[inline(always)]
+fn read_bit(registers: &mut impl RegisterAccess) -> u8 {
+    registers.write_data(Data::CS);
+    registers.write_data(Data::CS);
+    registers.write_data(Data::CS);
+    registers.write_data(Data::CS);
+    registers.write_data(Data::CS);
+    registers.write_data(Data::CS | Data::SCK);
+    u8::from(registers.read_data() & Data::SIO) >> 1
+}
+
+/// Read consecutive bytes into `out`.
+///
+/// This reads all bytes within a single routine, with the reading of each byte's bits unrolled.
+/// Bits are received from lowest to highest.
+#[cfg(not(feature = "host-stub"))]
+fn read_bytes(registers: &mut impl RegisterAccess, out: &mut [u8]) {
+    for byte in out {
+        let mut value = read_bit(registers);
+        value |= read_bit(registers) << 1;
+        value |= read_bit(registers) << 2;
+        value |= read_bit(registers) << 3;
+        value |= read_bit(registers) << 4;
+        value |= read_bit(registers) << 5;
+        value |= read_bit(registers) << 6;
+        value |= read_bit(registers) << 7;
+        let value = order_bits(value);
+        *byte = value;
+        trace!("RTC read: {:#04x}", value);
+    }
+}
+
+/// Read consecutive bytes from the stubbed RTC into `out`.
+///
+/// The stubbed RTC sends whole bytes, so `registers` is not used.
+#[cfg(feature = "host-stub")]
+fn read_bytes(_registers: &mut impl RegisterAccess, out: &mut [u8]) {
+    host_stub::read_bytes(out);
+    for byte in out {
+        *byte = order_bits(*byte);
+        trace!("RTC read: {:#04x}", byte);
     }
+}
+
+/// Read a single byte.
+#[cfg_attr(feature = "inline-more", inline)]
+fn read_byte(registers: &mut impl RegisterAccess) -> u8 {
+    let mut byte = 0;
+    read_bytes(registers, slice::from_mut(&mut byte));
     byte
 }
 
 // Write a single byte.
-fn write_byte(byte: u8) {
+#[cfg(not(feature = "host-stub"))]
+fn write_byte(registers: &mut impl RegisterAccess, byte: u8) {
+    trace!("RTC write: {:#04x}", byte);
+    let byte = order_bits(byte);
     for i in 0..8 {
-        unsafe {
-            let bit = (byte >> i << 1) & 2;
-            DATA.write_volatile(bit | Data::CS);
-            DATA.write_volatile(bit | Data::CS);
-            DATA.write_volatile(bit | Data::CS);
-            DATA.write_volatile(bit | Data::CS | Data::SCK);
-        }
+        let bit = (byte >> i << 1) & 2;
+        registers.write_data(bit | Data::CS);
+        registers.write_data(bit | Data::CS);
+        registers.write_data(bit | Data::CS);
+        registers.write_data(bit | Data::CS | Data::SCK);
     }
 }
 
+// Write a single byte to the stubbed RTC.
+//
+// The stubbed RTC receives whole bytes, so `registers` is not used.
+#[cfg(feature = "host-stub")]
+fn write_byte(_registers: &mut impl RegisterAccess, byte: u8) {
+    trace!("RTC write: {:#04x}", byte);
+    host_stub::write_byte(order_bits(byte));
+}
+
 /// The RTC's status register.
 ///
 /// This is an 8-bit representation of the various modes and states stored in the RTC itself. All
 /// bits except `POWER` are writable. Bits 0, 2, and 4 are unused and therefore should never be
 /// set.
-#[derive(Debug, Eq, PartialEq)]
-pub(crate) struct Status(u8);
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Status(u8);
 
 impl Status {
+    pub(crate) const EMPTY: Status = Status(0);
     pub(crate) const POWER: Status = Status(0b1000_0000);
     pub(crate) const HOUR_24: Status = Status(0b0100_0000);
+    pub(crate) const INT_ALARM: Status = Status(0b0010_0000);
+    pub(crate) const INT_PER_MINUTE: Status = Status(0b0000_1000);
+    pub(crate) const INT_PER_FREQUENCY: Status = Status(0b0000_0010);
+
+    /// The unused bits, which should never be set.
+    const RESERVED: u8 = 0b0001_0101;
+
+    /// The interrupt enable bits.
+    const INTERRUPTS: u8 = Self::INT_ALARM.0 | Self::INT_PER_MINUTE.0 | Self::INT_PER_FREQUENCY.0;
+
+    /// Interprets `value` as a `Status`, clearing any reserved bits rather than rejecting them.
+    ///
+    /// Some RTC chips leave reserved bits set harmlessly. All other bits are kept as-is.
+    pub(crate) fn from_lenient(value: u8) -> Self {
+        Status(value & !Self::RESERVED)
+    }
 
+    #[cfg_attr(feature = "inline-more", inline)]
     pub(crate) fn contains(&self, other: &Self) -> bool {
         self.0 & other.0 != 0
     }
+
+    /// Returns whether the RTC has lost power since it was last reset.
+    ///
+    /// If this is set, the date and time reported by the RTC are unreliable.
+    pub fn power_failure(&self) -> bool {
+        self.contains(&Self::POWER)
+    }
+
+    /// Returns whether the RTC reports hours in 24-hour mode.
+    pub fn hour_24(&self) -> bool {
+        self.contains(&Self::HOUR_24)
+    }
+
+    /// Returns the interrupt mode configured in the RTC.
+    ///
+    /// Returns `None` if more than one interrupt enable bit is set, as such combinations are not
+    /// represented by [`InterruptMode`].
+    pub fn interrupt_mode(&self) -> Option<InterruptMode> {
+        match self.0 & Self::INTERRUPTS {
+            0 => Some(InterruptMode::Off),
+            bits if bits == Self::INT_PER_MINUTE.0 => Some(InterruptMode::PerMinute),
+            bits if bits == Self::INT_PER_FREQUENCY.0 => Some(InterruptMode::PerFrequency),
+            bits if bits == Self::INT_ALARM.0 => Some(InterruptMode::Alarm),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the RTC's periodic frequency interrupt is enabled.
+    ///
+    /// This checks only bit 1 (`INTFE`), regardless of the other interrupt enable bits. While it is
+    /// set, the RTC drives its interrupt output at a fixed frequency rather than signaling the
+    /// time, which usually indicates a chip that has been left misconfigured.
+    pub fn frequency_output(&self) -> bool {
+        self.contains(&Self::INT_PER_FREQUENCY)
+    }
+
+    /// Returns this status with its interrupt enable bits replaced by those for `mode`.
+    ///
+    /// All other bits are kept as-is.
+    #[cfg(not(feature = "read-only"))]
+    fn with_interrupt_mode(self, mode: InterruptMode) -> Self {
+        let bits = match mode {
+            InterruptMode::Off => Self::EMPTY,
+            InterruptMode::PerMinute => Self::INT_PER_MINUTE,
+            InterruptMode::PerFrequency => Self::INT_PER_FREQUENCY,
+            InterruptMode::Alarm => Self::INT_ALARM,
+        };
+        Status(self.0 & !Self::INTERRUPTS | bits.0)
+    }
+}
+
+/// The condition under which the RTC raises an interrupt.
+///
+/// The RTC signals interrupts through the cartridge's interrupt line, which raises the GBA's
+/// Game Pak interrupt. The RTC's status register can enable only one of these at a time.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InterruptMode {
+    /// No interrupts are raised.
+    Off,
+    /// An interrupt is raised once every minute.
+    PerMinute,
+    /// An interrupt is raised at a fixed frequency.
+    PerFrequency,
+    /// An interrupt is raised when the RTC's alarm time is reached.
+    Alarm,
 }
 
 impl TryFrom<u8> for Status {
@@ -185,7 +623,7 @@ impl TryFrom<u8> for Status {
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         // Check for unused bits.
-        if value & 0b0001_0101 != 0 {
+        if value & Status::RESERVED != 0 {
             Err(Error::InvalidStatus(value))
         } else {
             Ok(Status(value))
@@ -195,59 +633,86 @@ impl TryFrom<u8> for Status {
 
 /// Attempt to obtain the `Status` register from the RTC.
 pub(crate) fn try_read_status() -> Result<Status, Error> {
-    // Disable interrupts, storing the previous value.
-    //
-    // This prevents interrupts while reading data from the device. This is necessary because GPIO
-    // reads data one bit at a time.
-    let previous_ime = unsafe { IME.read_volatile() };
-    unsafe { IME.write_volatile(false) };
+    read_status_byte()?.try_into()
+}
 
-    // Check if enabled.
-    if !is_enabled() {
-        return Err(Error::NotEnabled);
-    }
+/// Obtain the `Status` register from the RTC, clearing any reserved bits rather than rejecting
+/// them.
+pub(crate) fn try_read_status_lenient() -> Result<Status, Error> {
+    Ok(Status::from_lenient(read_status_byte()?))
+}
+
+/// Read the raw byte stored in the RTC's `Status` register, without validation.
+fn read_status_byte() -> Result<u8, Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    let status = read_status_transaction(registers);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
 
+    Ok(status)
+}
+
+/// Perform a single transaction reading the raw `Status` byte.
+///
+/// Interrupts must be disabled and GPIO must be enabled before calling this.
+fn read_status_transaction(registers: &mut impl RegisterAccess) -> u8 {
     // Request status.
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::CS | Data::SCK);
-        RW_MODE.write_volatile(RwMode::Write);
-    }
-    send_command(Command::ReadStatus);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command(registers, Command::ReadStatus);
 
     // Receive status.
-    unsafe {
-        RW_MODE.write_volatile(RwMode::Read);
-    }
-    let status = read_byte();
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::SCK);
-    }
+    registers.write_rw_mode(RwMode::Read);
+    let status = read_byte(registers);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
 
-    // Restore the previous interrupt enable value.
-    unsafe {
-        IME.write_volatile(previous_ime);
-    }
+    status
+}
 
-    status.try_into()
+/// Send an arbitrary command byte to the RTC and read back `out.len()` bytes into `out`.
+///
+/// # Safety
+/// No validation is performed on `command`. The caller must ensure that `command` is one the RTC
+/// responds to by outputting at least `out.len()` bytes, and that the RTC is left in a state that
+/// is compatible with any existing `Clock`.
+#[cfg(not(feature = "read-only"))]
+pub(crate) unsafe fn raw_command(command: u8, out: &mut [u8]) -> Result<(), Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    // Request.
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command_byte(registers, command);
+
+    // Receive.
+    registers.write_rw_mode(RwMode::Read);
+    read_bytes(registers, out);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    Ok(())
 }
 
 /// Enable operations with the RTC via General Purpose I/O (GPIO).
 pub(crate) fn enable() {
-    unsafe {
-        ENABLE.write_volatile(1);
-    }
+    Volatile.write(Register::Enable, 1);
 }
 
 /// Disable operations with the RTC via General Purpose I/O (GPIO).
-///
-/// This is only used for tests.
-#[cfg(test)]
 pub(crate) fn disable() {
-    unsafe {
-        ENABLE.write_volatile(0);
-    }
+    Volatile.write(Register::Enable, 0);
 }
 
 /// Check whether General Purpose I/O operations are enabled.
@@ -259,240 +724,539 @@ pub(crate) fn disable() {
 ///
 /// Additionally, this prevents issues with failing RTC chips, which could theoretically fail after
 /// being successfully enabled prior.
+#[cfg_attr(feature = "inline-more", inline)]
 fn is_enabled() -> bool {
-    unsafe {
-        // Reading `ENABLE` has varying effects depending on the environment. On mGBA, the
-        // previously stored `ENABLE` value is returned (usually `1`). On real hardware, it seems
-        // bits indicating the enabled GPIO pins are returned (for RTC-enabled carts, this is `7`).
-        // Carts without RTC (and mGBA) return `0`.
-        //
-        // In any case, it seems we can rely on this read returning `0` only if there is no RTC
-        // enabled.
-        ENABLE.read_volatile() != 0
-    }
+    // Reading `ENABLE` has varying effects depending on the environment. On mGBA, the previously
+    // stored `ENABLE` value is returned (usually `1`). On real hardware, it seems bits indicating
+    // the enabled GPIO pins are returned (for RTC-enabled carts, this is `7`). Carts without RTC
+    // (and mGBA) return `0`.
+    //
+    // In any case, it seems we can rely on this read returning `0` only if there is no RTC
+    // enabled.
+    Volatile.read(Register::Enable) != 0
 }
 
 pub(crate) fn reset() -> Result<(), Error> {
-    // Disable interrupts, storing the previous value.
-    //
-    // This prevents interrupts while reading data from the device. This is necessary because GPIO
-    // reads data one bit at a time.
-    let previous_ime = unsafe { IME.read_volatile() };
-    unsafe { IME.write_volatile(false) };
-
-    // Check if enabled.
-    if !is_enabled() {
-        return Err(Error::NotEnabled);
-    }
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
 
     // Request reset.
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::CS | Data::SCK);
-        RW_MODE.write_volatile(RwMode::Write);
-    }
-    send_command(Command::Reset);
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::SCK);
-    }
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command(registers, Command::Reset);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
 
-    // Restore the previous interrupt enable value.
-    unsafe {
-        IME.write_volatile(previous_ime);
-    }
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
 
     Ok(())
 }
 
 /// Attempt to read the current RTC date and time value as an `RtcOffset`.
-pub(crate) fn try_read_datetime_offset() -> Result<RtcDateTimeOffset, Error> {
-    // Disable interrupts, storing the previous value.
-    //
-    // This prevents interrupts while reading data from the device. This is necessary because GPIO
-    // reads data one bit at a time.
-    let previous_ime = unsafe { IME.read_volatile() };
-    unsafe { IME.write_volatile(false) };
+///
+/// The hour is interpreted according to `hour_mode`, which must match the RTC's current status.
+#[cfg_attr(feature = "inline-more", inline)]
+pub(crate) fn try_read_datetime_offset(hour_mode: HourMode) -> Result<RtcDateTimeOffset, Error> {
+    try_read_datetime_offset_into(hour_mode, &mut [0; DATETIME_BUFFER_LEN])
+}
 
-    // Check if enabled.
-    if !is_enabled() {
-        return Err(Error::NotEnabled);
-    }
+/// Attempt to read the current RTC date and time value as an `RtcOffset`, using `buffer` to hold
+/// the raw bytes received from the RTC.
+///
+/// The hour is interpreted according to `hour_mode`, which must match the RTC's current status.
+pub(crate) fn try_read_datetime_offset_into(
+    hour_mode: HourMode,
+    buffer: &mut [u8; DATETIME_BUFFER_LEN],
+) -> Result<RtcDateTimeOffset, Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    read_datetime_transaction(registers, buffer);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    decode_datetime_offset(buffer, hour_mode)
+}
+
+/// Attempt to read the current RTC date and time value as an `RtcOffset`, briefly restoring the
+/// previous interrupt enable value between each byte.
+///
+/// The hour is interpreted according to `hour_mode`, which must match the RTC's current status.
+pub(crate) fn try_read_datetime_offset_interruptible(
+    hour_mode: HourMode,
+) -> Result<RtcDateTimeOffset, Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    let mut buffer = [0; DATETIME_BUFFER_LEN];
+    read_datetime_transaction_interruptible(registers, &mut buffer, transaction.previous_ime);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    decode_datetime_offset(&buffer, hour_mode)
+}
+
+/// Attempt to read the raw year, month, day, weekday, hour, minute, and second bytes from the RTC.
+///
+/// The bytes are returned exactly as they are received, without any validation or decoding.
+pub(crate) fn try_read_datetime_bytes() -> Result<[u8; DATETIME_BUFFER_LEN], Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    let mut bytes = [0; DATETIME_BUFFER_LEN];
+    read_datetime_transaction(registers, &mut bytes);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    Ok(bytes)
+}
+
+/// Attempt to read both the `Status` register and the current RTC date and time value.
+///
+/// Both are read back-to-back within a single period of disabled interrupts, meaning the returned
+/// status reflects the same moment as the returned date and time.
+pub(crate) fn try_read_datetime_offset_and_status(
+    hour_mode: HourMode,
+) -> Result<(RtcDateTimeOffset, Status), Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    let status = read_status_transaction(registers);
+    let mut buffer = [0; DATETIME_BUFFER_LEN];
+    read_datetime_transaction(registers, &mut buffer);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    Ok((
+        decode_datetime_offset(&buffer, hour_mode)?,
+        status.try_into()?,
+    ))
+}
 
+/// Perform a single transaction reading the raw year, month, day, weekday, hour, minute, and second
+/// bytes into `buffer`.
+///
+/// Interrupts must be disabled and GPIO must be enabled before calling this.
+fn read_datetime_transaction(
+    registers: &mut impl RegisterAccess,
+    buffer: &mut [u8; DATETIME_BUFFER_LEN],
+) {
     // Request datetime.
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::CS | Data::SCK);
-        RW_MODE.write_volatile(RwMode::Write);
-    }
-    send_command(Command::ReadDateTime);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command(registers, Command::ReadDateTime);
 
     // Receive datetime.
-    unsafe {
-        RW_MODE.write_volatile(RwMode::Read);
-    }
-    let year = read_byte();
-    let month = read_byte();
-    let day = read_byte();
-    let _weekday = read_byte();
-    let hour = read_byte();
-    let minute = read_byte();
-    let second = read_byte();
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::SCK);
-    }
+    registers.write_rw_mode(RwMode::Read);
+    read_bytes(registers, buffer);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
+}
 
-    // Restore the previous interrupt enable value.
-    unsafe {
-        IME.write_volatile(previous_ime);
-    }
+/// Perform a single transaction reading the same bytes as `read_datetime_transaction()`, setting
+/// `IME` to `previous_ime` between each byte.
+///
+/// This allows pending interrupts to be serviced partway through the read. Between bytes, CS is
+/// held high and SCK is left high, so the RTC simply waits for the next clock. Interrupts must be
+/// disabled and GPIO must be enabled before calling this, and interrupts are disabled again
+/// when it returns.
+fn read_datetime_transaction_interruptible(
+    registers: &mut impl RegisterAccess,
+    buffer: &mut [u8; DATETIME_BUFFER_LEN],
+    previous_ime: bool,
+) {
+    // Request datetime.
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command(registers, Command::ReadDateTime);
+
+    // Receive datetime, yielding to interrupts after each byte.
+    registers.write_rw_mode(RwMode::Read);
+    for byte in buffer.iter_mut() {
+        read_bytes(registers, slice::from_mut(byte));
+        registers.write(Register::Ime, previous_ime.into());
+        registers.write(Register::Ime, 0);
+    }
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
+}
+
+/// Perform a single transaction reading the raw hour, minute, and second bytes into `buffer`.
+///
+/// Interrupts must be disabled and GPIO must be enabled before calling this.
+fn read_time_transaction(registers: &mut impl RegisterAccess, buffer: &mut [u8; 3]) {
+    // Request time.
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command(registers, Command::ReadTime);
+
+    // Receive time.
+    registers.write_rw_mode(RwMode::Read);
+    read_bytes(registers, buffer);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
+}
 
+/// Decode the raw bytes read by `read_datetime_transaction()` into an `RtcDateTimeOffset`.
+///
+/// The weekday byte is not needed, but is still validated to detect corrupt reads.
+fn decode_datetime_offset(
+    &[year, month, day, weekday, hour, minute, second]: &[u8; DATETIME_BUFFER_LEN],
+    hour_mode: HourMode,
+) -> Result<RtcDateTimeOffset, Error> {
+    let (year, month, day) = decode_date(year, month, day, weekday)?;
     Ok(RtcDateTimeOffset::new(
-        Bcd::try_from(year)?.into(),
-        Bcd::try_from(month)?.try_into()?,
-        Bcd::try_from(day)?.try_into()?,
-        Bcd::try_from(hour)?.try_into()?,
+        year,
+        month,
+        day,
+        decode_hour(hour, hour_mode)?,
         Bcd::try_from(minute)?.try_into()?,
         Bcd::try_from(second)?.try_into()?,
     ))
 }
 
-pub(crate) fn try_read_time_offset() -> Result<RtcTimeOffset, Error> {
-    // Disable interrupts, storing the previous value.
-    //
-    // This prevents interrupts while reading data from the device. This is necessary because GPIO
-    // reads data one bit at a time.
-    let previous_ime = unsafe { IME.read_volatile() };
-    unsafe { IME.write_volatile(false) };
+/// Decode the raw date bytes read by `read_datetime_transaction()`.
+///
+/// The weekday byte is not needed, but is still validated to detect corrupt reads.
+fn decode_date(year: u8, month: u8, day: u8, weekday: u8) -> Result<(Year, Month, Day), Error> {
+    let year = Bcd::try_from(year)?.into();
+    let month = Bcd::try_from(month)?.try_into()?;
+    let day = Bcd::try_from(day)?.try_into()?;
+    validate_date(&year, month, &day)?;
+    Bcd::try_from(weekday)?.validate_weekday()?;
+    Ok((year, month, day))
+}
 
-    // Check if enabled.
-    if !is_enabled() {
-        return Err(Error::NotEnabled);
-    }
+/// Attempt to read the current RTC date and time value, tolerating a corrupt time.
+///
+/// Returns the decoded value along with whether the time was decoded successfully. If any of the
+/// time bytes contain an invalid value, the returned value is midnight on the RTC's date. Invalid
+/// date bytes, as well as an RTC in test mode or 12-hour mode, still result in an error.
+pub(crate) fn try_read_datetime_offset_lenient(
+    hour_mode: HourMode,
+) -> Result<(RtcDateTimeOffset, bool), Error> {
+    decode_datetime_offset_lenient(&try_read_datetime_bytes()?, hour_mode)
+}
 
-    // Request time.
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::CS | Data::SCK);
-        RW_MODE.write_volatile(RwMode::Write);
+/// Decode the raw bytes read by `read_datetime_transaction()`, tolerating invalid time bytes.
+///
+/// See `try_read_datetime_offset_lenient()` for details.
+fn decode_datetime_offset_lenient(
+    &[year, month, day, weekday, hour, minute, second]: &[u8; DATETIME_BUFFER_LEN],
+    hour_mode: HourMode,
+) -> Result<(RtcDateTimeOffset, bool), Error> {
+    let (year, month, day) = decode_date(year, month, day, weekday)?;
+    let decode_time = || -> Result<(Hour, Minute, Second), Error> {
+        Ok((
+            decode_hour(hour, hour_mode)?,
+            Bcd::try_from(minute)?.try_into()?,
+            Bcd::try_from(second)?.try_into()?,
+        ))
+    };
+    match decode_time() {
+        Ok((hour, minute, second)) => Ok((
+            RtcDateTimeOffset::new(year, month, day, hour, minute, second),
+            true,
+        )),
+        // Only invalid values are tolerated. Errors indicating the RTC is in an unusable state are
+        // still returned.
+        Err(error) if error.is_transient() => Ok((
+            RtcDateTimeOffset::new(
+                year,
+                month,
+                day,
+                Hour(RangedU8::MIN),
+                Minute(RangedU8::MIN),
+                Second(RangedU8::MIN),
+            ),
+            false,
+        )),
+        Err(error) => Err(error),
     }
-    send_command(Command::ReadTime);
+}
 
-    // Receive time.
-    unsafe {
-        RW_MODE.write_volatile(RwMode::Read);
-    }
-    let hour = read_byte();
-    let minute = read_byte();
-    let second = read_byte();
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::SCK);
-    }
+/// Attempt to read the current RTC time value as an `RtcTimeOffset`.
+///
+/// The hour is interpreted according to `hour_mode`, which must match the RTC's current status.
+pub(crate) fn try_read_time_offset(hour_mode: HourMode) -> Result<RtcTimeOffset, Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
 
-    // Restore the previous interrupt enable value.
-    unsafe {
-        IME.write_volatile(previous_ime);
-    }
+    let mut bytes = [0; 3];
+    read_time_transaction(registers, &mut bytes);
+    let [hour, minute, second] = bytes;
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
 
     Ok(RtcTimeOffset::new(
-        Bcd::try_from(hour)?.try_into()?,
+        decode_hour(hour, hour_mode)?,
         Bcd::try_from(minute)?.try_into()?,
         Bcd::try_from(second)?.try_into()?,
     ))
 }
 
+/// Attempt to read the current RTC hour and minute as an `RtcTimeOffset`.
+///
+/// The seconds byte is never clocked out of the RTC. Instead, the transaction is terminated early
+/// after the minute byte by deasserting CS, which the S-3511A permits after any byte of a read.
+/// The returned offset therefore always has a seconds component of `0`.
+///
+/// The hour is interpreted according to `hour_mode`, which must match the RTC's current status.
+pub(crate) fn try_read_hour_minute_offset(hour_mode: HourMode) -> Result<RtcTimeOffset, Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    // Request time.
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command(registers, Command::ReadTime);
+
+    // Receive only the hour and minute, terminating the transaction before the seconds byte.
+    registers.write_rw_mode(RwMode::Read);
+    let mut bytes = [0; 2];
+    read_bytes(registers, &mut bytes);
+    let [hour, minute] = bytes;
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    Ok(RtcTimeOffset::new(
+        decode_hour(hour, hour_mode)?,
+        Bcd::try_from(minute)?.try_into()?,
+        Second(RangedU8::MIN),
+    ))
+}
+
 pub(crate) fn is_test_mode() -> Result<bool, Error> {
-    // Disable interrupts, storing the previous value.
-    //
-    // This prevents interrupts while reading data from the device. This is necessary because GPIO
-    // reads data one bit at a time.
-    let previous_ime = unsafe { IME.read_volatile() };
-    unsafe { IME.write_volatile(false) };
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
 
-    // Check if enabled.
-    if !is_enabled() {
-        return Err(Error::NotEnabled);
-    }
+    let mut bytes = [0; 3];
+    read_time_transaction(registers, &mut bytes);
+    let [_hour, _minute, second] = bytes;
 
-    // Request time.
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::CS | Data::SCK);
-        RW_MODE.write_volatile(RwMode::Write);
-    }
-    send_command(Command::ReadTime);
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
 
-    // Receive time.
-    unsafe {
-        RW_MODE.write_volatile(RwMode::Read);
-    }
-    let _hour = read_byte();
-    let _minute = read_byte();
-    let second = read_byte();
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::SCK);
-    }
+    // Check whether the test flag is set.
+    Ok(seconds_byte_is_test(second))
+}
 
-    // Restore the previous interrupt enable value.
-    unsafe {
-        IME.write_volatile(previous_ime);
+/// Determine whether the RTC actually operates in 24-hour mode.
+///
+/// The status register is read back first. If it reports 24-hour mode, the hour is also sampled
+/// to confirm that it is reported without the PM flag.
+pub(crate) fn supports_24_hour() -> Result<bool, Error> {
+    let status = try_read_status()?;
+    if !status.hour_24() {
+        return Ok(false);
     }
 
-    // Check whether the test flag is set.
-    Ok(second & 0b1000_0000 != 0)
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+    let registers = &mut Volatile;
+
+    let mut bytes = [0; 3];
+    read_time_transaction(registers, &mut bytes);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    Ok(hour_24_supported(status, bytes[0]))
+}
+
+/// Determine whether an RTC reporting `status` and the raw `hour` byte is operating in 24-hour
+/// mode.
+///
+/// An RTC that accepts the 24-hour bit but continues operating in 12-hour mode reports hours past
+/// noon with the PM flag set and a value of `12` or below. Hours before noon are reported the same
+/// in both modes, so this can only detect such an RTC while its hour is in the afternoon.
+fn hour_24_supported(status: Status, hour: u8) -> bool {
+    status.hour_24() && hour & 0b1000_0000 == 0
 }
 
 pub(crate) fn set_status(status: Status) -> Result<(), Error> {
-    // Disable interrupts, storing the previous value.
-    //
-    // This prevents interrupts while reading data from the device. This is necessary because GPIO
-    // reads data one bit at a time.
-    let previous_ime = unsafe { IME.read_volatile() };
-    unsafe { IME.write_volatile(false) };
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
 
-    // Check if enabled.
-    if !is_enabled() {
-        return Err(Error::NotEnabled);
-    }
+    write_status_transaction(&mut Volatile, status);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    Ok(())
+}
 
+/// Perform a single transaction writing `status` to the `Status` register.
+///
+/// Interrupts must be disabled and GPIO must be enabled before calling this.
+fn write_status_transaction(registers: &mut impl RegisterAccess, status: Status) {
     // Request status write.
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::CS | Data::SCK);
-        RW_MODE.write_volatile(RwMode::Write);
-    }
-    send_command(Command::WriteStatus);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::CS | Data::SCK);
+    registers.write_rw_mode(RwMode::Write);
+    send_command(registers, Command::WriteStatus);
 
     // Write the status.
-    write_byte(status.0);
-    unsafe {
-        DATA.write_volatile(Data::SCK);
-        DATA.write_volatile(Data::SCK);
-    }
+    write_byte(registers, status.0);
+    registers.write_data(Data::SCK);
+    registers.write_data(Data::SCK);
+}
 
-    // Restore the previous interrupt enable value.
-    unsafe {
-        IME.write_volatile(previous_ime);
-    }
+/// Write `mode` to the interrupt enable bits of the RTC's `Status` register.
+///
+/// All other writable bits are preserved from `status`, which should be the RTC's current status.
+#[cfg(not(feature = "read-only"))]
+pub(crate) fn set_interrupt_mode(status: Status, mode: InterruptMode) -> Result<(), Error> {
+    // The power bit is read-only, so it must not be written back.
+    set_status(Status(
+        status.with_interrupt_mode(mode).0 & !Status::POWER.0,
+    ))
+}
 
-    Ok(())
+/// A status pattern used to verify communication with the RTC.
+///
+/// This keeps the RTC in 24-hour mode and enables only the alarm interrupt, leaving the
+/// per-minute and frequency interrupts disabled so that no interrupt is raised on the Game Pak
+/// interrupt line while the pattern is written. The set bits are surrounded by clear ones, so a
+/// data line that never changes cannot produce it.
+#[cfg(not(feature = "read-only"))]
+const SELF_TEST_PATTERN: u8 = Status::HOUR_24.0 | Status::INT_ALARM.0;
+
+/// Verify that data can be both written to and read back from the RTC.
+///
+/// This writes `SELF_TEST_PATTERN` to the `Status` register and reads it back, restoring the
+/// previous status afterward. All of this is done within a single transaction, so the previous
+/// status is always restored once the pattern has been written.
+#[cfg(not(feature = "read-only"))]
+pub(crate) fn self_test() -> Result<(), Error> {
+    // Claim the GPIO port and disable interrupts for the duration of the transaction.
+    let transaction = Transaction::begin()?;
+
+    let readback = self_test_transaction(&mut Volatile);
+
+    // End the transaction, restoring the previous interrupt enable value.
+    drop(transaction);
+
+    check_self_test_readback(readback)
+}
+
+/// Perform a single transaction writing `SELF_TEST_PATTERN` to the `Status` register, reading it
+/// back, and restoring the previous status.
+///
+/// Returns the raw value read back after writing the pattern. Interrupts must be disabled and GPIO
+/// must be enabled before calling this.
+#[cfg(not(feature = "read-only"))]
+fn self_test_transaction(registers: &mut impl RegisterAccess) -> u8 {
+    let previous = read_status_transaction(registers);
+    write_status_transaction(registers, Status(SELF_TEST_PATTERN));
+    let readback = read_status_transaction(registers);
+    // The power bit is read-only, so it must not be written back.
+    write_status_transaction(registers, Status(previous & !Status::POWER.0));
+    readback
+}
+
+/// Check the value read back from the RTC after writing `SELF_TEST_PATTERN`.
+///
+/// A readback of all zeros or all ones indicates that the serial data line never changed while
+/// being sampled. Any other readback that does not match indicates that the GPIO port is enabled,
+/// but no RTC is storing what is written to it.
+#[cfg(not(feature = "read-only"))]
+fn check_self_test_readback(readback: u8) -> Result<(), Error> {
+    match readback {
+        0x00 | 0xff => Err(Error::BusStuck),
+        // The power bit may be set independently of what was written.
+        _ if readback & !Status::POWER.0 != SELF_TEST_PATTERN => Err(Error::NoDevice),
+        _ => Ok(()),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    #[cfg(not(feature = "msb-first"))]
+    use super::write_byte;
+    #[cfg(not(feature = "read-only"))]
     use super::{
-        Data,
+        check_self_test_readback,
+        self_test_transaction,
+        SELF_TEST_PATTERN,
+    };
+    use super::{
+        decode_datetime_offset,
+        decode_datetime_offset_lenient,
+        disable,
+        enable,
+        hour_24_supported,
+        order_bits,
+        pause_dma_channels,
+        read_byte,
+        read_bytes,
+        read_datetime_transaction,
+        read_datetime_transaction_interruptible,
+        read_time_transaction,
+        reset,
+        resume_dma_channels,
+        send_command,
+        set_pause_dma,
+        set_status,
+        supports_24_hour,
+        try_read_datetime_bytes,
+        try_read_datetime_offset,
+        try_read_datetime_offset_interruptible,
+        try_read_status,
+        try_read_time_offset,
+        Command,
+        Data,
+        InterruptMode,
+        Register,
+        RegisterAccess,
+        RwMode,
         Status,
+        Transaction,
+        Volatile,
+        BUSY,
+        DMA_CONTROL,
+        DMA_ENABLE,
+    };
+    use crate::{
+        date_time::{
+            HourMode,
+            RtcDateTimeOffset,
+            RtcTimeOffset,
+        },
+        Error,
     };
-    use crate::Error;
     use claims::{
         assert_err_eq,
+        assert_none,
+        assert_ok,
         assert_ok_eq,
+        assert_some_eq,
     };
+    use core::ptr::addr_of_mut;
     use gba_test::test;
+    use time_macros::datetime;
 
     #[test]
     fn data_bit_or_empty() {
@@ -612,6 +1376,23 @@ mod tests {
         assert_eq!(Data(7) & Data(7), Data(7));
     }
 
+    #[test]
+    #[cfg(not(feature = "msb-first"))]
+    fn order_bits_lsb_first() {
+        assert_eq!(order_bits(0b0010_1101), 0b0010_1101);
+    }
+
+    #[test]
+    #[cfg(feature = "msb-first")]
+    fn order_bits_msb_first() {
+        assert_eq!(order_bits(0b0010_1101), 0b1011_0100);
+    }
+
+    #[test]
+    fn order_bits_round_trip() {
+        assert_eq!(order_bits(order_bits(0b0010_1101)), 0b0010_1101);
+    }
+
     #[test]
     fn status_contains_power() {
         assert!(Status::POWER.contains(&Status::POWER));
@@ -680,4 +1461,740 @@ mod tests {
     fn status_from_all_valid_bits() {
         assert_ok_eq!(Status::try_from(0b1110_1010), Status(0b1110_1010));
     }
+
+    #[test]
+    fn status_from_lenient_reserved_bits() {
+        assert_eq!(Status::from_lenient(0b0000_0101), Status::EMPTY);
+        assert_err_eq!(
+            Status::try_from(0b0000_0101),
+            Error::InvalidStatus(0b0000_0101)
+        );
+    }
+
+    #[test]
+    fn status_from_lenient_keeps_valid_bits() {
+        assert_eq!(Status::from_lenient(0xff), Status(0b1110_1010));
+    }
+
+    #[test]
+    fn decode_datetime_offset_valid() {
+        assert_ok_eq!(
+            decode_datetime_offset(&[0x12, 0x12, 0x21, 0x05, 0x05, 0x23, 0x42], HourMode::H24),
+            assert_ok!(RtcDateTimeOffset::try_from(datetime!(2012-12-21 5:23:42)))
+        );
+    }
+
+    #[test]
+    fn decode_datetime_offset_invalid_weekday() {
+        assert_err_eq!(
+            decode_datetime_offset(&[0x12, 0x12, 0x21, 0x09, 0x05, 0x23, 0x42], HourMode::H24),
+            Error::InvalidWeekday(9)
+        );
+    }
+
+    #[test]
+    fn decode_datetime_offset_weekday_not_bcd() {
+        assert_err_eq!(
+            decode_datetime_offset(&[0x12, 0x12, 0x21, 0x0a, 0x05, 0x23, 0x42], HourMode::H24),
+            Error::InvalidBinaryCodedDecimal(0x0a)
+        );
+    }
+
+    #[test]
+    fn decode_datetime_offset_lenient_valid() {
+        assert_ok_eq!(
+            decode_datetime_offset_lenient(
+                &[0x12, 0x12, 0x21, 0x05, 0x05, 0x23, 0x42],
+                HourMode::H24
+            ),
+            (
+                assert_ok!(RtcDateTimeOffset::try_from(datetime!(2012-12-21 5:23:42))),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn decode_datetime_offset_lenient_invalid_second() {
+        assert_ok_eq!(
+            decode_datetime_offset_lenient(
+                &[0x12, 0x12, 0x21, 0x05, 0x05, 0x23, 0x61],
+                HourMode::H24
+            ),
+            (
+                assert_ok!(RtcDateTimeOffset::try_from(datetime!(2012-12-21 0:00))),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn decode_datetime_offset_lenient_invalid_month() {
+        assert_err_eq!(
+            decode_datetime_offset_lenient(
+                &[0x12, 0x13, 0x21, 0x05, 0x05, 0x23, 0x42],
+                HourMode::H24
+            ),
+            Error::InvalidMonth(13)
+        );
+    }
+
+    #[test]
+    fn decode_datetime_offset_lenient_test_mode() {
+        assert_err_eq!(
+            decode_datetime_offset_lenient(
+                &[0x12, 0x12, 0x21, 0x05, 0x05, 0x23, 0x80],
+                HourMode::H24
+            ),
+            Error::TestMode
+        );
+    }
+
+    #[test]
+    fn status_from_lenient_power() {
+        assert_eq!(Status::from_lenient(0b1001_0000), Status::POWER);
+    }
+
+    #[test]
+    fn status_from_interrupt_bits() {
+        assert_ok_eq!(Status::try_from(0b0000_0010), Status::INT_PER_FREQUENCY);
+        assert_ok_eq!(Status::try_from(0b0000_1000), Status::INT_PER_MINUTE);
+        assert_ok_eq!(Status::try_from(0b0010_0000), Status::INT_ALARM);
+    }
+
+    #[test]
+    fn status_interrupt_mode_off() {
+        assert_some_eq!(Status::HOUR_24.interrupt_mode(), InterruptMode::Off);
+    }
+
+    #[test]
+    fn status_interrupt_mode_per_minute() {
+        assert_some_eq!(
+            Status(0b0100_1000).interrupt_mode(),
+            InterruptMode::PerMinute
+        );
+    }
+
+    #[test]
+    fn status_interrupt_mode_per_frequency() {
+        assert_some_eq!(
+            Status(0b0100_0010).interrupt_mode(),
+            InterruptMode::PerFrequency
+        );
+    }
+
+    #[test]
+    fn status_interrupt_mode_alarm() {
+        assert_some_eq!(Status(0b0110_0000).interrupt_mode(), InterruptMode::Alarm);
+    }
+
+    #[test]
+    fn status_frequency_output() {
+        assert!(Status(0b0100_0010).frequency_output());
+    }
+
+    #[test]
+    fn status_frequency_output_with_other_interrupts() {
+        assert!(Status(0b0110_1010).frequency_output());
+    }
+
+    #[test]
+    fn status_frequency_output_clear() {
+        assert!(!Status::HOUR_24.frequency_output());
+        assert!(!Status(0b1110_1000).frequency_output());
+    }
+
+    #[test]
+    fn status_interrupt_mode_multiple() {
+        assert_none!(Status(0b0010_1000).interrupt_mode());
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn status_with_interrupt_mode_off() {
+        assert_eq!(
+            Status(0b0110_0000).with_interrupt_mode(InterruptMode::Off),
+            Status(0b0100_0000)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn status_with_interrupt_mode_per_minute() {
+        assert_eq!(
+            Status::HOUR_24.with_interrupt_mode(InterruptMode::PerMinute),
+            Status(0b0100_1000)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn status_with_interrupt_mode_per_frequency() {
+        assert_eq!(
+            Status::HOUR_24.with_interrupt_mode(InterruptMode::PerFrequency),
+            Status(0b0100_0010)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn status_with_interrupt_mode_alarm() {
+        assert_eq!(
+            Status(0b1100_1000).with_interrupt_mode(InterruptMode::Alarm),
+            Status(0b1110_0000)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn status_with_interrupt_mode_is_valid() {
+        for mode in [
+            InterruptMode::Off,
+            InterruptMode::PerMinute,
+            InterruptMode::PerFrequency,
+            InterruptMode::Alarm,
+        ] {
+            let status = Status::HOUR_24.with_interrupt_mode(mode);
+
+            assert_ok_eq!(Status::try_from(status.0), status);
+            assert_some_eq!(status.interrupt_mode(), mode);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_readback_matches() {
+        assert_ok!(check_self_test_readback(SELF_TEST_PATTERN));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_readback_matches_with_power() {
+        assert_ok!(check_self_test_readback(SELF_TEST_PATTERN | 0b1000_0000));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_readback_all_zeros() {
+        assert_err_eq!(check_self_test_readback(0x00), Error::BusStuck);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_readback_all_ones() {
+        assert_err_eq!(check_self_test_readback(0xff), Error::BusStuck);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_readback_mismatch() {
+        assert_err_eq!(check_self_test_readback(0b0100_0000), Error::NoDevice);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_readback_partial_mismatch() {
+        assert_err_eq!(
+            check_self_test_readback(SELF_TEST_PATTERN & !Status::INT_ALARM.0),
+            Error::NoDevice
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_offset_matches_time_offset() {
+        enable();
+        assert_ok!(reset());
+        assert_ok!(set_status(Status::HOUR_24));
+
+        let datetime_offset = assert_ok!(try_read_datetime_offset(HourMode::H24));
+        let time_offset = assert_ok!(try_read_time_offset(HourMode::H24));
+
+        assert_eq!(RtcTimeOffset::from(datetime_offset), time_offset);
+    }
+
+    #[test]
+    fn transaction_busy() {
+        // Simulate a transaction already being in progress.
+        unsafe { addr_of_mut!(BUSY).write_volatile(true) };
+
+        let result = Transaction::begin();
+        unsafe { addr_of_mut!(BUSY).write_volatile(false) };
+
+        assert_err_eq!(result, Error::Busy);
+    }
+
+    #[test]
+    fn pause_dma_channels_disables_enabled() {
+        let mut controls = [0xb640, 0x0000, 0x8000, 0x3640];
+        let pointers = [0, 1, 2, 3].map(|i| unsafe { controls.as_mut_ptr().add(i) });
+
+        let previous = unsafe { pause_dma_channels(&pointers) };
+
+        assert_eq!(previous, [0xb640, 0x0000, 0x8000, 0x3640]);
+        assert_eq!(controls, [0x3640, 0x0000, 0x0000, 0x3640]);
+    }
+
+    #[test]
+    fn resume_dma_channels_restores_enabled() {
+        let mut controls = [0x3640, 0x0000, 0x0000, 0x3640];
+        let pointers = [0, 1, 2, 3].map(|i| unsafe { controls.as_mut_ptr().add(i) });
+
+        unsafe { resume_dma_channels(&pointers, [0xb640, 0x0000, 0x8000, 0x3640]) };
+
+        assert_eq!(controls, [0xb640, 0x0000, 0x8000, 0x3640]);
+    }
+
+    #[test]
+    fn resume_dma_channels_leaves_disabled_untouched() {
+        // A disabled channel that was reconfigured during the transaction is not overwritten.
+        let mut controls = [0x0000, 0x1234, 0x0000, 0x0000];
+        let pointers = [0, 1, 2, 3].map(|i| unsafe { controls.as_mut_ptr().add(i) });
+
+        unsafe { resume_dma_channels(&pointers, [0x0000; 4]) };
+
+        assert_eq!(controls, [0x0000, 0x1234, 0x0000, 0x0000]);
+    }
+
+    /// The source and destination of the transfer configured by [`configure_vblank_dma()`].
+    static mut DMA_WORDS: [u32; 2] = [0; 2];
+
+    /// Configures DMA 3 to copy a single word within `DMA_WORDS` on every VBlank.
+    ///
+    /// Returns the value written to the channel's control register. As the transfer only starts on
+    /// VBlank, the channel remains enabled and idle until then.
+    fn configure_vblank_dma() -> u16 {
+        let source = addr_of_mut!(DMA_WORDS).cast::<u32>();
+        unsafe {
+            (0x0400_00d4 as *mut *mut u32).write_volatile(source);
+            (0x0400_00d8 as *mut *mut u32).write_volatile(source.add(1));
+            (0x0400_00dc as *mut u16).write_volatile(1);
+        }
+        // Enabled, started on VBlank, 32-bit, repeating.
+        let control = DMA_ENABLE | 0x1000 | 0x0400 | 0x0200;
+        unsafe { DMA_CONTROL[3].write_volatile(control) };
+        control
+    }
+
+    #[test]
+    fn pause_dma_channels_hardware() {
+        let control = configure_vblank_dma();
+
+        let previous = unsafe { pause_dma_channels(&DMA_CONTROL) };
+        let paused = unsafe { DMA_CONTROL[3].read_volatile() };
+        unsafe { resume_dma_channels(&DMA_CONTROL, previous) };
+        let resumed = unsafe { DMA_CONTROL[3].read_volatile() };
+        // Leave DMA 3 disabled for other tests.
+        unsafe { DMA_CONTROL[3].write_volatile(0) };
+
+        assert_eq!(paused, control & !DMA_ENABLE);
+        assert_eq!(resumed, control);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn transaction_pauses_dma_hardware() {
+        enable();
+        let control = configure_vblank_dma();
+        set_pause_dma(true);
+
+        let transaction = assert_ok!(Transaction::begin());
+        let paused = unsafe { DMA_CONTROL[3].read_volatile() };
+        drop(transaction);
+        let resumed = unsafe { DMA_CONTROL[3].read_volatile() };
+        set_pause_dma(false);
+        // Leave DMA 3 disabled for other tests.
+        unsafe { DMA_CONTROL[3].write_volatile(0) };
+
+        assert_eq!(paused, control & !DMA_ENABLE);
+        assert_eq!(resumed, control);
+    }
+
+    #[test]
+    fn read_status_busy() {
+        // Simulate a transaction already being in progress.
+        unsafe { addr_of_mut!(BUSY).write_volatile(true) };
+
+        let result = try_read_status();
+        unsafe { addr_of_mut!(BUSY).write_volatile(false) };
+
+        assert_err_eq!(result, Error::Busy);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn transaction_reentrant() {
+        enable();
+
+        let transaction = assert_ok!(Transaction::begin());
+        assert_err_eq!(Transaction::begin(), Error::Busy);
+        drop(transaction);
+
+        assert_ok!(Transaction::begin());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn transaction_not_enabled_restores_state() {
+        // Manually enable RTC.
+        enable();
+        Volatile.write(Register::Ime, 1);
+
+        assert_err_eq!(Transaction::begin(), Error::NotEnabled);
+
+        assert_eq!(Volatile.read(Register::Ime), 1);
+        assert!(!unsafe { addr_of_mut!(BUSY).read_volatile() });
+    }
+
+    /// Read the year, month, and day bytes from the RTC using the given `read` function.
+    fn read_date_bytes<F>(read: F) -> [u8; 3]
+    where
+        F: FnOnce() -> [u8; 3],
+    {
+        let transaction = assert_ok!(Transaction::begin());
+        let registers = &mut Volatile;
+        registers.write_data(Data::SCK);
+        registers.write_data(Data::CS | Data::SCK);
+        registers.write_rw_mode(RwMode::Write);
+        send_command(registers, Command::ReadDateTime);
+        registers.write_rw_mode(RwMode::Read);
+        let bytes = read();
+        registers.write_data(Data::SCK);
+        registers.write_data(Data::SCK);
+        drop(transaction);
+        bytes
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_bytes_matches_read_byte() {
+        enable();
+        assert_ok!(reset());
+        assert_ok!(set_status(Status::HOUR_24));
+
+        let batched = read_date_bytes(|| {
+            let mut bytes = [0; 3];
+            read_bytes(&mut Volatile, &mut bytes);
+            bytes
+        });
+        let sequential = read_date_bytes(|| {
+            [
+                read_byte(&mut Volatile),
+                read_byte(&mut Volatile),
+                read_byte(&mut Volatile),
+            ]
+        });
+
+        assert_eq!(batched, sequential);
+    }
+
+    /// Registers that record every value written to `DATA`.
+    ///
+    /// Reads of `DATA` respond with the bits of `response` on the serial data line, in the order
+    /// the RTC transfers them.
+    struct FakeRegisters {
+        writes: [u16; 64],
+        len: usize,
+        response: u8,
+        reads: u8,
+    }
+
+    impl FakeRegisters {
+        fn new(response: u8) -> Self {
+            Self {
+                writes: [0; 64],
+                len: 0,
+                response: order_bits(response),
+                reads: 0,
+            }
+        }
+
+        fn data_writes(&self) -> &[u16] {
+            &self.writes[..self.len]
+        }
+    }
+
+    impl RegisterAccess for FakeRegisters {
+        fn read(&mut self, register: Register) -> u16 {
+            if register != Register::Data {
+                return 0;
+            }
+            let bit = (self.response >> self.reads) & 1;
+            self.reads += 1;
+            u16::from(bit) << 1
+        }
+
+        fn write(&mut self, register: Register, value: u16) {
+            if register == Register::Data {
+                self.writes[self.len] = value;
+                self.len += 1;
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "msb-first"))]
+    fn send_command_read_status_bits() {
+        let mut registers = FakeRegisters::new(0);
+
+        send_command(&mut registers, Command::ReadStatus);
+
+        // `0x63`, sent from highest bit to lowest. Each bit is held with CS high and SCK low, then
+        // clocked by raising SCK.
+        assert_eq!(
+            registers.data_writes(),
+            [
+                4, 4, 4, 5, // 0
+                6, 6, 6, 7, // 1
+                6, 6, 6, 7, // 1
+                4, 4, 4, 5, // 0
+                4, 4, 4, 5, // 0
+                4, 4, 4, 5, // 0
+                6, 6, 6, 7, // 1
+                6, 6, 6, 7, // 1
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "msb-first"))]
+    fn write_byte_bits() {
+        let mut registers = FakeRegisters::new(0);
+
+        write_byte(&mut registers, Status::HOUR_24.0);
+
+        // `0x40`, sent from lowest bit to highest.
+        assert_eq!(
+            registers.data_writes(),
+            [
+                4, 4, 4, 5, // 0
+                4, 4, 4, 5, // 0
+                4, 4, 4, 5, // 0
+                4, 4, 4, 5, // 0
+                4, 4, 4, 5, // 0
+                4, 4, 4, 5, // 0
+                6, 6, 6, 7, // 1
+                4, 4, 4, 5, // 0
+            ]
+        );
+    }
+
+    #[test]
+    fn read_byte_bits() {
+        let mut registers = FakeRegisters::new(0xa5);
+
+        assert_eq!(read_byte(&mut registers), 0xa5);
+        // Each bit is sampled after holding SCK low and then raising it.
+        assert_eq!(registers.data_writes()[..6], [4, 4, 4, 4, 4, 5]);
+        assert_eq!(registers.data_writes().len(), 48);
+    }
+
+    /// Registers that respond to reads of `DATA` with the bits of `response`, in the order the RTC
+    /// transfers them, and record every value written to `IME`.
+    struct FakeRtc {
+        response: [u8; 7],
+        reads: usize,
+        ime_writes: [u16; 16],
+        ime_len: usize,
+    }
+
+    impl FakeRtc {
+        fn new(response: [u8; 7]) -> Self {
+            Self {
+                response: response.map(order_bits),
+                reads: 0,
+                ime_writes: [0; 16],
+                ime_len: 0,
+            }
+        }
+
+        fn ime_writes(&self) -> &[u16] {
+            &self.ime_writes[..self.ime_len]
+        }
+    }
+
+    impl RegisterAccess for FakeRtc {
+        fn read(&mut self, register: Register) -> u16 {
+            if register != Register::Data {
+                return 0;
+            }
+            let bit = (self.response[self.reads / 8] >> (self.reads % 8)) & 1;
+            self.reads += 1;
+            u16::from(bit) << 1
+        }
+
+        fn write(&mut self, register: Register, value: u16) {
+            if register == Register::Ime {
+                self.ime_writes[self.ime_len] = value;
+                self.ime_len += 1;
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_transaction_matches() {
+        let mut registers = FakeRtc::new([Status::HOUR_24.0, SELF_TEST_PATTERN, 0, 0, 0, 0, 0]);
+
+        assert_ok!(check_self_test_readback(self_test_transaction(
+            &mut registers
+        )));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn self_test_transaction_no_device() {
+        // A cartridge without an RTC never reports its GPIO port as enabled, so this case can't be
+        // reproduced on hardware. Instead, nothing on the port stores what is written, so the
+        // previous value is read back again.
+        let mut registers = FakeRtc::new([Status::HOUR_24.0, Status::HOUR_24.0, 0, 0, 0, 0, 0]);
+
+        assert_err_eq!(
+            check_self_test_readback(self_test_transaction(&mut registers)),
+            Error::NoDevice
+        );
+    }
+
+    #[test]
+    fn read_datetime_transaction_interruptible_matches_atomic() {
+        let response = [0x12, 0x06, 0x15, 0x05, 0x13, 0x45, 0x30];
+        let mut atomic_registers = FakeRtc::new(response);
+        let mut interruptible_registers = FakeRtc::new(response);
+        let mut atomic = [0; 7];
+        let mut interruptible = [0; 7];
+
+        read_datetime_transaction(&mut atomic_registers, &mut atomic);
+        read_datetime_transaction_interruptible(
+            &mut interruptible_registers,
+            &mut interruptible,
+            true,
+        );
+
+        assert_eq!(atomic, response);
+        assert_eq!(interruptible, atomic);
+        assert_eq!(
+            decode_datetime_offset(&interruptible, HourMode::H24),
+            decode_datetime_offset(&atomic, HourMode::H24)
+        );
+    }
+
+    #[test]
+    fn read_datetime_transaction_interruptible_yields_between_bytes() {
+        let mut registers = FakeRtc::new([0; 7]);
+
+        read_datetime_transaction_interruptible(&mut registers, &mut [0; 7], true);
+
+        assert_eq!(
+            registers.ime_writes(),
+            [1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0]
+        );
+    }
+
+    #[test]
+    fn read_datetime_transaction_interruptible_keeps_interrupts_disabled() {
+        let mut registers = FakeRtc::new([0; 7]);
+
+        read_datetime_transaction_interruptible(&mut registers, &mut [0; 7], false);
+
+        assert!(registers.ime_writes().iter().all(|&ime| ime == 0));
+    }
+
+    #[test]
+    fn hour_24_supported_chip_in_24_hour_mode() {
+        // 3:45:30 PM, reported in 24-hour form.
+        let mut registers = FakeRtc::new([0x15, 0x45, 0x30, 0, 0, 0, 0]);
+        let mut bytes = [0; 3];
+
+        read_time_transaction(&mut registers, &mut bytes);
+
+        assert!(hour_24_supported(Status::HOUR_24, bytes[0]));
+    }
+
+    #[test]
+    fn hour_24_supported_chip_stays_12_hour() {
+        // 3:45:30 PM, reported in 12-hour form despite the status reporting 24-hour mode.
+        let mut registers = FakeRtc::new([0x83, 0x45, 0x30, 0, 0, 0, 0]);
+        let mut bytes = [0; 3];
+
+        read_time_transaction(&mut registers, &mut bytes);
+
+        assert!(!hour_24_supported(Status::HOUR_24, bytes[0]));
+    }
+
+    #[test]
+    fn hour_24_supported_status_not_24_hour() {
+        assert!(!hour_24_supported(Status(0), 0x15));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn supports_24_hour_after_set_status() {
+        enable();
+        assert_ok!(reset());
+        assert_ok!(set_status(Status::HOUR_24));
+
+        assert_ok_eq!(supports_24_hour(), true);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn try_read_datetime_offset_interruptible_matches_atomic() {
+        enable();
+        assert_ok!(reset());
+        assert_ok!(set_status(Status::HOUR_24));
+
+        let atomic = assert_ok!(try_read_datetime_offset(HourMode::H24));
+        let interruptible = assert_ok!(try_read_datetime_offset_interruptible(HourMode::H24));
+
+        // The RTC may tick over between the two reads.
+        assert!((interruptible - atomic).0.get() <= 1);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn try_read_datetime_bytes_after_reset() {
+        enable();
+        assert_ok!(reset());
+        assert_ok!(set_status(Status::HOUR_24));
+
+        let bytes = assert_ok!(try_read_datetime_bytes());
+
+        // A reset RTC reads 2000-01-01.
+        assert_eq!(bytes[..3], [0x00, 0x01, 0x01]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn try_read_datetime_bytes_not_enabled() {
+        disable();
+
+        assert_err_eq!(try_read_datetime_bytes(), Error::NotEnabled);
+    }
 }
@@ -0,0 +1,88 @@
+//! An in-memory stand-in for the RTC, used in place of the hardware when the `host-stub` feature
+//! is enabled.
+//!
+//! The GPIO registers are backed by ordinary memory, and commands are interpreted a byte at a time
+//! rather than a bit at a time. The stubbed RTC always reports 2000-01-01 00:00:00 and never
+//! advances, meaning a `Clock` always reads back exactly the date and time it was created or last
+//! written with.
+
+use super::{
+    Command,
+    RwMode,
+    Status,
+};
+
+/// Backing memory for `DATA`.
+pub(super) static mut DATA: u16 = 0;
+
+/// Backing memory for `RW_MODE`.
+pub(super) static mut RW_MODE: u16 = RwMode::Read as u16;
+
+/// Backing memory for `ENABLE`.
+pub(super) static mut ENABLE: u16 = 0;
+
+/// Backing memory for `IME`.
+pub(super) static mut IME: u16 = 0;
+
+/// Backing memory for `DMA_CONTROL`.
+pub(super) static mut DMA_CONTROL: [u16; 4] = [0; 4];
+
+/// The raw date and time bytes reported by the stubbed RTC.
+///
+/// This is 2000-01-01 00:00:00, which was a Saturday.
+const DATETIME: [u8; 7] = [0x00, 0x01, 0x01, 0x06, 0x00, 0x00, 0x00];
+
+/// The most recently sent command.
+static mut COMMAND: u8 = 0;
+
+/// The number of bytes read or written since the most recent command was sent.
+static mut POSITION: usize = 0;
+
+/// The stubbed RTC's status register.
+///
+/// This starts out in 24-hour mode, as if the RTC had already been initialized.
+static mut STATUS: u8 = Status::HOUR_24.0;
+
+/// Receive a command byte.
+pub(super) fn send_command(command: u8) {
+    unsafe {
+        COMMAND = command;
+        POSITION = 0;
+        if command == Command::Reset as u8 {
+            STATUS = Status::EMPTY.0;
+        }
+    }
+}
+
+/// Respond to the most recently sent command, writing one byte into each element of `out`.
+///
+/// Commands that the stubbed RTC does not recognize respond with zeros.
+pub(super) fn read_bytes(out: &mut [u8]) {
+    for byte in out {
+        unsafe {
+            *byte = match COMMAND {
+                command if command == Command::ReadStatus as u8 => STATUS,
+                command if command == Command::ReadDateTime as u8 => {
+                    DATETIME.get(POSITION).copied().unwrap_or(0)
+                }
+                command if command == Command::ReadTime as u8 => {
+                    DATETIME.get(POSITION + 4).copied().unwrap_or(0)
+                }
+                _ => 0,
+            };
+            POSITION += 1;
+        }
+    }
+}
+
+/// Receive a byte written as part of the most recently sent command.
+///
+/// Only writes to the status register are stored. The power bit is read-only and is never set.
+pub(super) fn write_byte(byte: u8) {
+    unsafe {
+        if COMMAND == Command::WriteStatus as u8 && POSITION == 0 {
+            STATUS = byte & !Status::POWER.0;
+        }
+        POSITION += 1;
+    }
+}
@@ -0,0 +1,164 @@
+//! Low-level access to the RTC's General Purpose I/O (GPIO) port.
+//!
+//! These functions interact with the hardware directly, bypassing [`Clock`]. They are intended for
+//! custom initialization sequences that [`Clock::new()`] and [`ClockBuilder`] do not cover.
+//!
+//! While none of these functions are `unsafe` to call, they make no attempt to keep any existing
+//! `Clock` consistent with the RTC. For example, calling [`reset()`] resets the date and time
+//! stored on the RTC, and disabling GPIO with [`disable()`] causes every subsequent `Clock` method
+//! that communicates with the RTC to return [`Error::NotEnabled`]. Any `Clock` in use should be
+//! recreated after using these functions to change the RTC's state.
+//!
+//! # Example
+//! ``` no_run
+//! use gba_clock::hw;
+//!
+//! hw::enable();
+//! let status = hw::read_status().expect("could not read the RTC's status");
+//! if status.power_failure() {
+//!     hw::reset().expect("could not reset the RTC");
+//! }
+//! hw::disable();
+//! ```
+//!
+//! [`Clock`]: crate::Clock
+//! [`Clock::new()`]: crate::Clock::new()
+//! [`ClockBuilder`]: crate::ClockBuilder
+
+use crate::{
+    gpio,
+    Error,
+    Status,
+};
+
+/// Enable communication with the RTC via GPIO.
+///
+/// This must be called before any other interaction with the RTC. [`Clock::new()`] calls this
+/// automatically.
+///
+/// [`Clock::new()`]: crate::Clock::new()
+pub fn enable() {
+    gpio::enable();
+}
+
+/// Disable communication with the RTC via GPIO.
+///
+/// Any interaction with the RTC after this is called will return [`Error::NotEnabled`] until
+/// [`enable()`] is called again.
+pub fn disable() {
+    gpio::disable();
+}
+
+/// Reset the RTC.
+///
+/// This clears the RTC's stored date and time as well as its `Status` register.
+///
+/// # Errors
+/// Returns [`Error::NotEnabled`] if GPIO is not readable, which occurs if [`enable()`] has not been
+/// called or if no RTC is present. Returns [`Error::Busy`] if another interaction with the RTC is
+/// already in progress.
+///
+/// # Example
+/// ``` no_run
+/// use gba_clock::hw;
+///
+/// hw::enable();
+/// hw::reset().expect("could not reset the RTC");
+/// ```
+pub fn reset() -> Result<(), Error> {
+    gpio::reset()
+}
+
+/// Read the RTC's `Status` register.
+///
+/// # Errors
+/// Returns [`Error::NotEnabled`] if GPIO is not readable, which occurs if [`enable()`] has not been
+/// called or if no RTC is present. Returns [`Error::InvalidStatus`] if the value read sets any
+/// reserved bits.
+///
+/// # Example
+/// ``` no_run
+/// use gba_clock::hw;
+///
+/// hw::enable();
+/// let status = hw::read_status().expect("could not read the RTC's status");
+/// let hour_24 = status.hour_24();
+/// ```
+pub fn read_status() -> Result<Status, Error> {
+    gpio::try_read_status()
+}
+
+/// Set whether DMA is paused while communicating with the RTC.
+///
+/// When this is enabled, every enabled DMA channel is disabled at the start of each interaction
+/// with the RTC and re-enabled at its end, in the same way that the interrupt enable value is
+/// saved and restored. This bounds the length of each interaction, during which interrupts are
+/// disabled, as DMA halts the CPU while it transfers. It does not make reads more reliable: the
+/// GBA drives the RTC's serial clock, so a DMA transfer between bits only lengthens a bit period
+/// and cannot corrupt the bits being transferred.
+///
+/// **Re-enabling a DMA channel restarts it.** The hardware reloads the channel's source,
+/// destination, and word count, so a channel does not continue where it was paused. For example,
+/// a sound FIFO DMA restarts from the beginning of its audio buffer, and an HBlank DMA restarts
+/// from the top of its table partway through a frame. Only enable this if no active channel
+/// depends on continuing where it left off.
+///
+/// By default, this is disabled. This applies to every subsequent interaction with the RTC,
+/// including those made by every `Clock`.
+pub fn set_pause_dma(pause_dma: bool) {
+    gpio::set_pause_dma(pause_dma);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        disable,
+        enable,
+        read_status,
+        reset,
+    };
+    use crate::Error;
+    use claims::{
+        assert_err_eq,
+        assert_ok,
+    };
+    use gba_test::test;
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn enable_read_status_disable() {
+        enable();
+        assert_ok!(read_status());
+        disable();
+
+        assert_err_eq!(read_status(), Error::NotEnabled);
+
+        // Leave GPIO enabled for other tests.
+        enable();
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn reset_not_enabled() {
+        enable();
+
+        assert_err_eq!(reset(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_status_not_enabled() {
+        enable();
+
+        assert_err_eq!(read_status(), Error::NotEnabled);
+    }
+}
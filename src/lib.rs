@@ -5,8 +5,10 @@
 //!
 //! # Example
 //! Access to the RTC is done through the [`Clock`](https://docs.rs/gba_clock/latest/gba_clock/struct.Clock.html) type. Create a `Clock` using the current time and use the returned instance to access the current time.
-//!
-//! ``` no_run
+// The RTC is only reachable on the GBA itself, so this example is only run when the `host-stub`
+// feature replaces it with an in-memory stand-in.
+#![cfg_attr(feature = "host-stub", doc = "```")]
+#![cfg_attr(not(feature = "host-stub"), doc = "``` no_run")]
 //! use gba_clock::Clock;
 //! use time::{
 //!     Date,
@@ -33,13 +35,47 @@
 #![cfg_attr(test, test_runner(gba_test::runner))]
 #![cfg_attr(test, reexport_test_harness_main = "test_harness")]
 
+#[cfg(all(feature = "host-stub", target_arch = "arm"))]
+compile_error!("the `host-stub` feature replaces the RTC and must not be enabled for the GBA");
+
 mod bcd;
+mod builder;
+mod cached;
+mod components;
 mod date_time;
 mod error;
 mod gpio;
+pub mod hw;
+#[cfg(feature = "async")]
+mod next_second;
+mod ranged;
+mod redacted;
+#[cfg(feature = "replay")]
+mod replay;
+mod snapshot;
 
+pub use builder::ClockBuilder;
+pub use cached::CachedClock;
+pub use components::DateTimeComponents;
+pub use date_time::{
+    days_in_month,
+    rtc_origin,
+    RtcDateTimeOffset,
+    RTC_ORIGIN_YEAR,
+};
 pub use error::Error;
+pub use gpio::{
+    InterruptMode,
+    Status,
+};
+#[cfg(feature = "async")]
+pub use next_second::NextSecond;
+pub use redacted::RedactedClock;
+#[cfg(feature = "replay")]
+pub use replay::ReplayClock;
+pub use snapshot::Snapshot;
 
+use core::cmp::Ordering;
 #[cfg(feature = "serde")]
 use core::{
     fmt,
@@ -47,20 +83,43 @@ use core::{
     str,
 };
 use date_time::{
-    RtcDateTimeOffset,
+    is_leap_day,
+    iso_week,
+    HourMode,
     RtcTimeOffset,
+    RTC_MAX_SECONDS,
+};
+#[cfg(not(feature = "read-only"))]
+use date_time::{
+    replace_day,
+    replace_month,
+    replace_year,
+    validate_year,
 };
-use deranged::RangedU32;
 use gpio::{
     enable,
     is_test_mode,
-    reset,
     set_status,
+    supports_24_hour,
+    try_read_datetime_bytes,
     try_read_datetime_offset,
+    try_read_datetime_offset_and_status,
+    try_read_datetime_offset_interruptible,
+    try_read_datetime_offset_into,
+    try_read_datetime_offset_lenient,
+    try_read_hour_minute_offset,
     try_read_status,
     try_read_time_offset,
-    Status,
+    DATETIME_BUFFER_LEN,
+};
+#[cfg(not(feature = "read-only"))]
+use gpio::{
+    raw_command,
+    self_test,
+    set_interrupt_mode,
+    try_read_status_lenient,
 };
+use ranged::RangedU32;
 #[cfg(feature = "serde")]
 use serde::{
     de,
@@ -73,6 +132,7 @@ use serde::{
         Visitor,
     },
     ser::{
+        Error as _,
         Serialize,
         SerializeStruct,
         Serializer,
@@ -80,8 +140,12 @@ use serde::{
 };
 use time::{
     Date,
+    Duration,
+    Month,
+    OffsetDateTime,
     PrimitiveDateTime,
     Time,
+    UtcOffset,
 };
 
 /// Access to the Real Time Clock.
@@ -89,6 +153,10 @@ use time::{
 /// Instantiating a `Clock` initializes the relevant registers for interacting with the RTC,
 /// allowing subsequent reads of the RTC's stored date and time. Dates and times are represented
 /// using types from the [`time`] crate.
+///
+/// If the `read-only` feature is enabled, all methods for writing the date and time are removed,
+/// along with `Clock::self_test()`, `Clock::raw_command()`, and `Clock::set_interrupt_mode()`. Only
+/// the initialization performed when constructing a `Clock` is written to the RTC.
 #[derive(Debug)]
 pub struct Clock {
     /// The base date from which dates and times are calculated.
@@ -102,389 +170,6198 @@ pub struct Clock {
     /// This is used to calculate the current date and time by calculating how much time has
     /// elapsed on the RTC past this offset and adding this value to the `base_date`.
     rtc_offset: RtcDateTimeOffset,
+
+    /// The format in which the RTC reports hours.
+    hour_mode: HourMode,
+
+    /// The UTC offset of the stored date and time.
+    utc_offset: UtcOffset,
+
+    /// A cosmetic adjustment applied to the date and time returned by reads.
+    display_shift: Duration,
+
+    /// The date and time captured by [`Clock::pause()`], if the `Clock` is paused.
+    ///
+    /// This is stored without the display shift applied.
+    paused: Option<PrimitiveDateTime>,
+
+    /// The simulated RTC value, if the `Clock` was created with [`Clock::new_software()`].
+    ///
+    /// This is advanced only by [`Clock::tick()`], and is read in place of the RTC's value.
+    software_offset: Option<RtcDateTimeOffset>,
+
+    /// The number of times a read is retried after a transient error.
+    retries: u8,
+
+    /// Whether the RTC is switched back to 24-hour mode when a read finds it in 12-hour mode.
+    auto_fix_hour_mode: bool,
+
+    /// Whether reserved bits in the RTC's status are ignored during initialization.
+    lenient_status: bool,
+
+    /// Scratch space for the raw bytes received from the RTC by [`Clock::read_datetime_mut()`].
+    ///
+    /// Storing this within the `Clock` avoids allocating a buffer on the stack for every read.
+    read_buffer: [u8; DATETIME_BUFFER_LEN],
 }
 
 impl Clock {
+    /// The latest date and time that can be read from a `Clock`.
+    ///
+    /// The RTC only has a resolution of one second, so this is the final whole second of the
+    /// latest date representable by [`Date`]. Reading a stored date and time past this value
+    /// returns [`Error::Overflow`]. See [`Clock::would_overflow()`].
+    pub const MAX_DATETIME: PrimitiveDateTime = PrimitiveDateTime::new(
+        Date::MAX,
+        match Time::from_hms(23, 59, 59) {
+            Ok(time) => time,
+            Err(_) => unreachable!(),
+        },
+    );
+
     /// Creates a new `Clock` set at the given `datetime`.
     ///
+    /// This uses the default configuration. To configure the `Clock` on construction, use a
+    /// [`ClockBuilder`] instead.
+    ///
     /// Note that this does not actually change the stored date and time in the RTC itself. While
     /// RTC values are writable on real hardware, they are often not writable in GBA emulators.
     /// Therefore, the date and time are stored as being offset from the current RTC date and time
     /// to maintain maximum compatibility.
     pub fn new(datetime: PrimitiveDateTime) -> Result<Self, Error> {
+        ClockBuilder::new().build(datetime)
+    }
+
+    /// Creates a new `Clock` set at the given `datetime`, failing if the RTC reports a power
+    /// failure.
+    ///
+    /// Unlike [`Clock::new()`], which silently resets an RTC that has lost power, this returns
+    /// [`Error::PowerFailure`] without resetting the RTC, allowing the caller to decide whether to
+    /// reset it (such as with [`Clock::new()`]) or to handle it some other way. Otherwise, this
+    /// behaves the same as [`Clock::new()`].
+    ///
+    /// This is equivalent to building with [`ClockBuilder::reset_on_power_failure()`] disabled.
+    pub fn new_strict(datetime: PrimitiveDateTime) -> Result<Self, Error> {
+        ClockBuilder::new()
+            .reset_on_power_failure(false)
+            .build(datetime)
+    }
+
+    /// Creates a new `Clock` set at the given `datetime`, assuming the RTC is already initialized.
+    ///
+    /// Unlike [`Clock::new()`], this does not reset the RTC or check it for a power failure or
+    /// test mode, and it does not write to the RTC's status. It only enables communication with
+    /// the RTC and reads its current value. This avoids the cost of initialization when the RTC
+    /// is known to be in a good state, such as after a soft reset of a game that already created
+    /// a `Clock`.
+    ///
+    /// The RTC's status is still read to confirm that it can be communicated with. A disconnected
+    /// or disabled serial data line reads as all zeros, which would otherwise be interpreted as a
+    /// valid date and time. If the status is all zeros, [`Error::BusStuck`] is returned.
+    ///
+    /// # Precondition
+    /// The RTC must have previously been initialized in 24-hour mode, such as by creating a
+    /// `Clock` with [`Clock::new()`], and must not have lost power since. If this does not hold,
+    /// reads from the returned `Clock` may fail or return incorrect values.
+    pub fn new_assume_initialized(datetime: PrimitiveDateTime) -> Result<Self, Error> {
         // Enable operations with the RTC via General Purpose I/O (GPIO).
         enable();
 
-        // Initialize the RTC itself.
-        reset()?;
-        // If the power bit is active, we need to reset.
-        let status = try_read_status()?;
-        if status.contains(&Status::POWER) {
-            reset()?;
+        // An initialized RTC always has the 24-hour bit set, so an empty status means the serial
+        // data line is not changing.
+        if try_read_status()? == Status::EMPTY {
+            return Err(Error::BusStuck);
         }
-        // If we are in test mode, we need to reset.
-        if is_test_mode()? {
-            reset()?;
+
+        let rtc_offset = try_read_datetime_offset(HourMode::H24)?;
+        Ok(Self::from_parts(
+            datetime.date(),
+            rtc_offset - datetime.time().into(),
+        ))
+    }
+
+    /// Creates a `Clock` directly from its stored base date and RTC offset.
+    ///
+    /// The created `Clock` will read midnight on `base_date` at the moment the RTC's value is
+    /// `rtc_offset`. This allows creating a `Clock` from an offset precomputed using
+    /// [`RtcDateTimeOffset::try_from()`].
+    ///
+    /// This does not interact with the RTC at all. Reads will only succeed if the RTC has
+    /// previously been initialized, such as by creating a `Clock` with [`Clock::new()`]. All other
+    /// configuration is set to its default value.
+    pub fn from_parts(base_date: Date, rtc_offset: RtcDateTimeOffset) -> Self {
+        Self {
+            base_date,
+            rtc_offset,
+            hour_mode: HourMode::H24,
+            utc_offset: UtcOffset::UTC,
+            display_shift: Duration::ZERO,
+            paused: None,
+            software_offset: None,
+            retries: 0,
+            auto_fix_hour_mode: false,
+            lenient_status: false,
+            read_buffer: [0; DATETIME_BUFFER_LEN],
         }
-        // Set to 24-hour time.
-        set_status(Status::HOUR_24)?;
+    }
 
-        let rtc_offset = try_read_datetime_offset()?;
+    /// Creates a software `Clock` set at `datetime`, whose date and time advance only when
+    /// [`Clock::tick()`] is called.
+    ///
+    /// This does not interact with the RTC at all. Instead, the `Clock` simulates the RTC's raw
+    /// value using a counter stored within it, which is used everywhere the RTC's value would
+    /// otherwise be read. Reading, writing, pausing, and resuming the date and time therefore all
+    /// behave as they would with an RTC that only advances when [`Clock::tick()`] is called,
+    /// including the values returned by [`Clock::read_raw_offset()`] and
+    /// [`Clock::snapshot()`]. This is useful for testing, and for emulators that do not advance
+    /// the RTC.
+    ///
+    /// Methods that can only be answered by the RTC itself, such as reading its [`Status`], return
+    /// [`Error::SoftwareClock`]. A software `Clock` also cannot be serialized with `serde`, as
+    /// deserializing would create a `Clock` reading from the RTC.
+    ///
+    /// As the RTC only has a resolution of one second, any sub-second component of `datetime` is
+    /// discarded, just as when creating a `Clock` with [`Clock::new()`].
+    pub fn new_software(datetime: PrimitiveDateTime) -> Self {
+        Self {
+            software_offset: Some(datetime.time().into()),
+            ..Self::from_parts(datetime.date(), RtcDateTimeOffset(RangedU32::MIN))
+        }
+    }
 
-        Ok(Self {
-            base_date: datetime.date(),
-            rtc_offset: rtc_offset - datetime.time().into(),
-        })
+    /// Packs the stored date and time into a fixed-size array of bytes.
+    ///
+    /// This is a compact alternative to serializing with `serde`, suitable for writing directly to
+    /// SRAM. The layout is, in little-endian byte order:
+    ///
+    /// | Bytes  | Contents                                 |
+    /// |--------|------------------------------------------|
+    /// | `0..2` | The base date's year, as an `i16`.       |
+    /// | `2..4` | The base date's ordinal day, as a `u16`. |
+    /// | `4..8` | The raw RTC offset, as a `u32`.          |
+    ///
+    /// As with serialization, only the stored date and time are included. All other configuration
+    /// is not preserved. The bytes can be restored using [`Clock::from_bytes()`].
+    ///
+    /// Note that the simulated RTC value of a software `Clock` (see [`Clock::new_software()`]) is
+    /// not included. Unlike serialization, this does not fail for a software `Clock`, but the
+    /// restored `Clock` reads from the RTC and will not return the same date and time.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        // The `time` crate limits years to a range well within an `i16`.
+        bytes[0..2].copy_from_slice(&(self.base_date.year() as i16).to_le_bytes());
+        bytes[2..4].copy_from_slice(&self.base_date.ordinal().to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.rtc_offset.get().to_le_bytes());
+        bytes
     }
 
-    /// Reads the currently stored date and time.
-    pub fn read_datetime(&self) -> Result<PrimitiveDateTime, Error> {
-        let rtc_offset = try_read_datetime_offset()?;
+    /// Restores a `Clock` from bytes created by [`Clock::to_bytes()`].
+    ///
+    /// The RTC is checked in the same way as when deserializing a `Clock` with `serde`: it is
+    /// enabled and switched to 24-hour mode, and [`Error::PowerFailure`] or [`Error::TestMode`] is
+    /// returned if it is in an unusable state.
+    ///
+    /// If the bytes do not contain a valid date, [`Error::InvalidComponent`] is returned. If they
+    /// contain an RTC offset beyond the end of 2099, [`Error::Overflow`] is returned.
+    pub fn from_bytes(bytes: &[u8; 8]) -> Result<Self, Error> {
+        let clock = Self::decode_bytes(bytes)?;
 
-        let duration = if rtc_offset.0 >= self.rtc_offset.0 {
-            RtcDateTimeOffset(unsafe { rtc_offset.0.unchecked_sub(self.rtc_offset.0.get()) }).into()
-        } else {
-            RtcDateTimeOffset(unsafe {
-                RangedU32::MAX
-                    .unchecked_sub(self.rtc_offset.0.get())
-                    .unchecked_add(rtc_offset.0.get())
-                    .unchecked_add(1)
-            })
-            .into()
-        };
+        // Enable operations with the RTC via General Purpose I/O (GPIO).
+        enable();
+        set_status(Status::HOUR_24)?;
+        // If the power bit is active, the clock is unreadable.
+        if try_read_status()?.contains(&Status::POWER) {
+            return Err(Error::PowerFailure);
+        }
+        // If we are in test mode, the clock is unreadable.
+        if is_test_mode()? {
+            return Err(Error::TestMode);
+        }
 
-        self.base_date
-            .midnight()
-            .checked_add(duration)
-            .ok_or(Error::Overflow)
+        Ok(clock)
     }
 
-    /// Writes a new date and time.
+    /// Creates a new `Clock` set at the time the crate was built.
     ///
-    /// Note that this does not actually change the stored date and time in the RTC itself. While
-    /// RTC values are writable on real hardware, they are often not writable in GBA emulators.
-    /// Therefore, the date and time are stored as being offset from the current RTC date and time
-    /// to maintain maximum compatibility.
-    pub fn write_datetime(&mut self, datetime: PrimitiveDateTime) -> Result<(), Error> {
-        let rtc_offset = try_read_datetime_offset()?;
-        self.base_date = datetime.date();
-        self.rtc_offset = rtc_offset - datetime.time().into();
-        Ok(())
+    /// The build time is taken from the `GBA_CLOCK_BUILD_TIME` environment variable when this
+    /// crate is compiled, in the form `YYYY-MM-DD HH:MM:SS` accepted by
+    /// [`Clock::from_build_time_str()`]. This crate cannot set the variable itself. Since a build
+    /// script can only set environment variables for its own crate, it must instead be set in the
+    /// environment that invokes `cargo`, such as through the `[env]` table of
+    /// `.cargo/config.toml` or by running, for example:
+    ///
+    /// ``` text
+    /// GBA_CLOCK_BUILD_TIME="$(date -u '+%Y-%m-%d %H:%M:%S')" cargo build --release
+    /// ```
+    ///
+    /// Compilation fails if the variable is not set. Note that changing the variable does not
+    /// cause this crate to be rebuilt on its own, so the build time may be stale in incremental
+    /// builds.
+    ///
+    /// If the variable is malformed, [`Error::InvalidComponent`] is returned. Otherwise, this
+    /// behaves the same as [`Clock::new()`].
+    #[cfg(feature = "build-time")]
+    pub fn new_at_build_time() -> Result<Self, Error> {
+        Self::new(Self::from_build_time_str(env!("GBA_CLOCK_BUILD_TIME"))?)
     }
 
-    /// Reads the currently stored date.
-    pub fn read_date(&self) -> Result<Date, Error> {
-        let rtc_offset = try_read_datetime_offset()?;
-
-        let duration = if rtc_offset.0 >= self.rtc_offset.0 {
-            RtcDateTimeOffset(unsafe { rtc_offset.0.unchecked_sub(self.rtc_offset.0.get()) }).into()
-        } else {
-            RtcDateTimeOffset(unsafe {
-                RangedU32::MAX
-                    .unchecked_sub(self.rtc_offset.0.get())
-                    .unchecked_add(rtc_offset.0.get())
-                    .unchecked_add(1)
-            })
-            .into()
+    /// Parses a build timestamp of the form `YYYY-MM-DD HH:MM:SS`.
+    ///
+    /// A `T` may be used in place of the space separating the date and time, as in ISO 8601. This
+    /// is the format expected in the `GBA_CLOCK_BUILD_TIME` environment variable when the
+    /// `build-time` feature is enabled. As this is a `const fn`, it can be used to parse a
+    /// timestamp at compile time.
+    ///
+    /// If `timestamp` is not in this form, or does not describe a valid date and time,
+    /// [`Error::InvalidComponent`] is returned.
+    pub const fn from_build_time_str(timestamp: &str) -> Result<PrimitiveDateTime, Error> {
+        let bytes = timestamp.as_bytes();
+        if bytes.len() != 19
+            || bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || (bytes[10] != b' ' && bytes[10] != b'T')
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+        {
+            return Err(Error::InvalidComponent);
+        }
+        let (Some(year), Some(month), Some(day), Some(hour), Some(minute), Some(second)) = (
+            parse_ascii_digits(bytes, 0, 4),
+            parse_ascii_digits(bytes, 5, 2),
+            parse_ascii_digits(bytes, 8, 2),
+            parse_ascii_digits(bytes, 11, 2),
+            parse_ascii_digits(bytes, 14, 2),
+            parse_ascii_digits(bytes, 17, 2),
+        ) else {
+            return Err(Error::InvalidComponent);
+        };
+        let Some(month) = month_from_number(month as u8) else {
+            return Err(Error::InvalidComponent);
+        };
+        let Ok(date) = Date::from_calendar_date(year as i32, month, day as u8) else {
+            return Err(Error::InvalidComponent);
+        };
+        let Ok(time) = Time::from_hms(hour as u8, minute as u8, second as u8) else {
+            return Err(Error::InvalidComponent);
         };
+        Ok(PrimitiveDateTime::new(date, time))
+    }
 
-        self.base_date.checked_add(duration).ok_or(Error::Overflow)
+    /// Unpacks bytes created by [`Clock::to_bytes()`] without interacting with the RTC.
+    fn decode_bytes(bytes: &[u8; 8]) -> Result<Self, Error> {
+        let [year_0, year_1, ordinal_0, ordinal_1, offset_0, offset_1, offset_2, offset_3] = *bytes;
+        let base_date = Date::from_ordinal_date(
+            i16::from_le_bytes([year_0, year_1]).into(),
+            u16::from_le_bytes([ordinal_0, ordinal_1]),
+        )?;
+        let rtc_offset =
+            RangedU32::new(u32::from_le_bytes([offset_0, offset_1, offset_2, offset_3]))
+                .ok_or(Error::Overflow)?;
+        Ok(Self::from_parts(base_date, RtcDateTimeOffset(rtc_offset)))
     }
 
-    /// Writes a new date.
+    /// Returns a view of this `Clock` whose [`Debug`](core::fmt::Debug) output does not reveal the
+    /// stored date and time.
     ///
-    /// This preserves the stored time.
+    /// The regular `Debug` output includes the base date and RTC offset, from which the stored
+    /// date and time can be calculated. The returned view prints only a fingerprint of these
+    /// instead, making it suitable for logs that should not expose the time. See
+    /// [`RedactedClock`] for more details.
+    pub fn debug_redacted(&self) -> RedactedClock<'_> {
+        RedactedClock::new(self)
+    }
+
+    /// Returns a view of this `Clock` that reuses previously calculated dates and times.
     ///
-    /// Note that this does not actually change the stored date in the RTC itself. While RTC values
-    /// are writable on real hardware, they are often not writable in GBA emulators. Therefore, the
-    /// date and time are stored as being offset from the current RTC date and time to maintain
-    /// maximum compatibility.
-    pub fn write_date(&mut self, date: Date) -> Result<(), Error> {
-        let rtc_offset = try_read_datetime_offset()?;
-        self.base_date = date;
-        // Calculate the current time offset.
-        let current_time_offset: RtcTimeOffset = if rtc_offset.0 >= self.rtc_offset.0 {
-            RtcDateTimeOffset(unsafe { rtc_offset.0.unchecked_sub(self.rtc_offset.0.get()) }).into()
-        } else {
-            RtcDateTimeOffset(unsafe {
-                RangedU32::MAX
-                    .unchecked_sub(self.rtc_offset.0.get())
-                    .unchecked_add(rtc_offset.0.get())
-                    .unchecked_add(1)
-            })
-            .into()
-        };
-        self.rtc_offset =
-            RtcDateTimeOffset(unsafe { rtc_offset.0.unchecked_sub(current_time_offset.0.get()) });
-        Ok(())
+    /// Calculating a date from the RTC's value requires software division on the GBA. The
+    /// returned view remembers its previous results and reuses them while they are still correct,
+    /// which is useful when polling the date and time many times per second. See [`CachedClock`]
+    /// for more details.
+    pub fn cached(&self) -> CachedClock<'_> {
+        CachedClock::new(self)
     }
 
-    /// Reads the currently stored time.
+    /// Sets the number of times reads are retried after failing with a transient error.
     ///
-    /// This is always faster than using [`Clock::read_datetime()`], as it only requires reading
-    /// three bytes from the RTC instead of seven.
-    pub fn read_time(&self) -> Result<Time, Error> {
-        let rtc_time_offset = try_read_time_offset()?;
-        let stored_time_offset: RtcTimeOffset = self.rtc_offset.into();
+    /// When a read from the RTC fails with an error for which [`Error::is_transient()`] is `true`,
+    /// the read is retried up to `retries` additional times before the error is returned. Each
+    /// retry re-issues the full GPIO transaction with the RTC. This applies to all `read_*`
+    /// methods.
+    ///
+    /// By default, no retries are performed. This configuration is not preserved when the `Clock`
+    /// is serialized.
+    pub fn with_retries(&mut self, retries: u8) {
+        self.retries = retries;
+    }
 
-        Ok(if rtc_time_offset.0 >= stored_time_offset.0 {
-            RtcTimeOffset(unsafe { rtc_time_offset.0.unchecked_sub(stored_time_offset.0.get()) })
-                .into()
-        } else {
-            RtcTimeOffset(unsafe {
-                RangedU32::MAX
-                    .unchecked_sub(stored_time_offset.0.get())
-                    .unchecked_add(rtc_time_offset.0.get())
-                    .unchecked_add(1)
-            })
-            .into()
-        })
+    /// Sets whether the RTC is automatically switched back to 24-hour mode when a read finds it in
+    /// 12-hour mode.
+    ///
+    /// If the RTC is knocked into 12-hour mode after the `Clock` is created, reads fail with
+    /// [`Error::AmPmBitPresent`]. When this is enabled, such a read instead writes the 24-hour
+    /// flag to the RTC's status register and is then attempted once more. **Note that this writes
+    /// to the RTC.** This applies to all `read_*` methods.
+    ///
+    /// By default, this is disabled. This configuration is not preserved when the `Clock` is
+    /// serialized.
+    #[cfg(not(feature = "read-only"))]
+    pub fn auto_fix_hour_mode(&mut self, auto_fix_hour_mode: bool) {
+        self.auto_fix_hour_mode = auto_fix_hour_mode;
     }
 
-    /// Writes a new time.
+    /// Returns the UTC offset of the stored date and time.
+    pub fn utc_offset(&self) -> UtcOffset {
+        self.utc_offset
+    }
+
+    /// Sets the UTC offset of the stored date and time.
     ///
-    /// This preserves the stored date.
+    /// The RTC has no concept of time zones. This offset is only used when converting between the
+    /// stored date and time and values that are defined in UTC, such as Unix timestamps. It does
+    /// not change the stored date and time.
     ///
-    /// Note that this does not actually change the stored time in the RTC itself. While RTC values
-    /// are writable on real hardware, they are often not writable in GBA emulators. Therefore, the
-    /// date and time are stored as being offset from the current RTC date and time to maintain
-    /// maximum compatibility.
-    pub fn write_time(&mut self, time: Time) -> Result<(), Error> {
-        let rtc_time_offset = try_read_time_offset()?;
-        let stored_time_offset = RtcTimeOffset::from(self.rtc_offset);
+    /// By default, the stored date and time are assumed to be UTC. This configuration is not
+    /// preserved when the `Clock` is serialized.
+    pub fn set_utc_offset(&mut self, utc_offset: UtcOffset) {
+        self.utc_offset = utc_offset;
+    }
 
-        let current_time: Time = if rtc_time_offset.0 >= stored_time_offset.0 {
-            RtcTimeOffset(unsafe { rtc_time_offset.0.unchecked_sub(stored_time_offset.0.get()) })
-                .into()
-        } else {
-            RtcTimeOffset(unsafe {
-                RangedU32::MAX
-                    .unchecked_sub(stored_time_offset.0.get())
-                    .unchecked_add(rtc_time_offset.0.get())
-                    .unchecked_add(1)
-            })
-            .into()
-        };
+    /// Returns the display shift applied to the date and time returned by reads.
+    pub fn display_shift(&self) -> Duration {
+        self.display_shift
+    }
 
-        // This difference will be within ±86,399. It can therefore fit within an i32.
-        let delta = (current_time - time).whole_seconds() as i32;
-        if delta.is_negative() {
-            self.rtc_offset -=
-                RtcDateTimeOffset(unsafe { RangedU32::new_unchecked(delta.unsigned_abs()) });
-        } else {
-            self.rtc_offset +=
-                RtcDateTimeOffset(unsafe { RangedU32::new_unchecked(delta.unsigned_abs()) });
+    /// Sets a display shift to be applied to the date and time returned by reads.
+    ///
+    /// This is a purely cosmetic adjustment, such as for a manual daylight saving time toggle that
+    /// moves the displayed time forward by an hour. Unlike the UTC offset, it has no meaning beyond
+    /// changing what is displayed. The shift is added to the values returned by every read of the
+    /// stored date and time, such as [`Clock::read_datetime()`], [`Clock::read_date()`],
+    /// [`Clock::read_time()`], [`Clock::snapshot()`], and [`Clock::seconds_of_day()`], and
+    /// therefore also to methods built on them, such as [`Clock::is_after()`]. It is not applied
+    /// to the RTC's raw values, to the elapsed time returned by [`Clock::read_delta()`] and
+    /// [`Clock::elapsed_core()`], or to Unix timestamps.
+    ///
+    /// Writes are never shifted: the date and time passed to the `write_*` methods are stored
+    /// as-is, and the shift is applied on top of them when they are read back.
+    ///
+    /// `shift` must be within the range of -24 hours to +24 hours, inclusive. Other values will
+    /// return [`Error::InvalidComponent`]. By default, no shift is applied. This configuration is
+    /// not preserved when the `Clock` is serialized.
+    pub fn set_display_shift(&mut self, shift: Duration) -> Result<(), Error> {
+        if shift.abs() > Duration::DAY {
+            return Err(Error::InvalidComponent);
         }
-
+        self.display_shift = shift;
         Ok(())
     }
-}
 
-#[cfg(feature = "serde")]
-impl Serialize for Clock {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut r#struct = serializer.serialize_struct("Clock", 2)?;
-        r#struct.serialize_field("base_date", &self.base_date)?;
-        r#struct.serialize_field("rtc_offset", &self.rtc_offset)?;
-        r#struct.end()
+    /// Verifies that the RTC can be communicated with.
+    ///
+    /// This writes a known pattern to the RTC's status register and reads it back, restoring the
+    /// previous status afterward within the same transaction. If the value read back is all zeros
+    /// or all ones, the serial data line is not changing and [`Error::BusStuck`] is returned. If
+    /// any other value that does not match the pattern is read back, the GPIO port is enabled but
+    /// no RTC is responding, and [`Error::NoDevice`] is returned. These are more specific
+    /// diagnostics than [`Error::NotEnabled`], which only indicates that the GPIO port itself is
+    /// not enabled.
+    ///
+    /// The pattern keeps the RTC in 24-hour mode and leaves the per-minute and frequency
+    /// interrupts disabled. It does briefly enable the alarm interrupt, so an alarm that matches
+    /// the current time may raise the Game Pak interrupt while the test runs.
+    #[cfg(not(feature = "read-only"))]
+    pub fn self_test(&self) -> Result<(), Error> {
+        self.require_rtc()?;
+        self_test()
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de> Deserialize<'de> for Clock {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    /// Sets the condition under which the RTC raises an interrupt.
+    ///
+    /// This writes the interrupt enable bits of the RTC's status register, preserving all other
+    /// settings. The RTC's interrupts are raised through the Game Pak interrupt, which must be
+    /// enabled separately to be handled.
+    ///
+    /// Note that this only configures the RTC. Setting the alarm time itself is not yet supported.
+    #[cfg(not(feature = "read-only"))]
+    pub fn set_interrupt_mode(&mut self, mode: InterruptMode) -> Result<(), Error> {
+        self.require_rtc()?;
+        let status = if self.lenient_status {
+            try_read_status_lenient()?
+        } else {
+            try_read_status()?
+        };
+        set_interrupt_mode(status, mode)
+    }
+
+    /// Sends an arbitrary command byte to the RTC and reads back `read_len` bytes into `out`.
+    ///
+    /// This performs the same sequence used for every interaction with the RTC: the chip is
+    /// selected, `command` is sent, and `read_len` bytes are clocked out. It is intended as an
+    /// escape hatch for experimenting with RTC behavior not otherwise supported by this crate.
+    /// Any remaining bytes of `out` past `read_len` are left unchanged.
+    ///
+    /// # Panics
+    /// Panics if `read_len` is greater than `out.len()`.
+    ///
+    /// # Safety
+    /// This bypasses all validation. The caller must ensure that `command` is one the RTC responds
+    /// to by outputting at least `read_len` bytes. Commands that change the RTC's state, such as
+    /// resetting it or changing its status, can leave it in a state incompatible with this
+    /// `Clock`, causing subsequent reads to fail or return incorrect values.
+    #[cfg(not(feature = "read-only"))]
+    pub unsafe fn raw_command(
+        &self,
+        command: u8,
+        read_len: usize,
+        out: &mut [u8],
+    ) -> Result<(), Error> {
+        self.require_rtc()?;
+        unsafe { raw_command(command, &mut out[..read_len]) }
+    }
+
+    /// Performs the given read, retrying on transient errors as configured by
+    /// [`Clock::with_retries()`].
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn retry<T, F>(&self, mut read: F) -> Result<T, Error>
     where
-        D: Deserializer<'de>,
+        F: FnMut() -> Result<T, Error>,
     {
-        enum Field {
-            BaseDate,
-            RtcOffset,
+        fix_hour_mode(
+            self.auto_fix_hour_mode,
+            || retry(self.retries, &mut read),
+            || set_status(Status::HOUR_24),
+        )
+    }
+
+    /// Reads the currently stored date and time.
+    ///
+    /// The raw bytes received from the RTC are held in a buffer on the stack for the duration of
+    /// the read. To reuse a buffer stored within the `Clock` instead, use
+    /// [`Clock::read_datetime_mut()`].
+    ///
+    /// If a display shift has been set using [`Clock::set_display_shift()`], it is applied to the
+    /// returned date and time.
+    ///
+    /// While the `Clock` is paused (see [`Clock::pause()`]), the date and time captured when
+    /// pausing is returned without reading from the RTC. A software `Clock` (see
+    /// [`Clock::new_software()`]) likewise never reads from the RTC.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn read_datetime(&self) -> Result<PrimitiveDateTime, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return datetime;
         }
+        let rtc_offset = self.read_offset()?;
+        self.shifted_datetime_at(rtc_offset)
+    }
 
-        impl<'de> Deserialize<'de> for Field {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: Deserializer<'de>,
-            {
-                struct FieldVisitor;
+    /// Reads the currently stored date and time, clamping to [`Clock::MAX_DATETIME`] rather than
+    /// overflowing.
+    ///
+    /// This returns the same value as [`Clock::read_datetime()`], except that where it would
+    /// return [`Error::Overflow`], [`Clock::MAX_DATETIME`] is returned instead. Other than
+    /// [`Error::Underflow`], which can only be caused by a negative display shift, the only errors
+    /// returned are therefore those caused by failing to communicate with the RTC or by invalid
+    /// values read from it.
+    ///
+    /// Note that clamping hides the overflow: once the stored date and time has passed
+    /// [`Clock::MAX_DATETIME`], this returns the same value on every read. Use
+    /// [`Clock::would_overflow()`] to detect this case.
+    pub fn read_datetime_clamped(&self) -> Result<PrimitiveDateTime, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return clamp_overflow(datetime);
+        }
+        let rtc_offset = self.read_offset()?;
+        clamp_overflow(self.shifted_datetime_at(rtc_offset))
+    }
 
-                impl<'de> Visitor<'de> for FieldVisitor {
-                    type Value = Field;
+    /// Calculates the stored date and time at the point the RTC reads `rtc_offset`, with the
+    /// display shift applied.
+    fn shifted_datetime_at(
+        &self,
+        rtc_offset: RtcDateTimeOffset,
+    ) -> Result<PrimitiveDateTime, Error> {
+        apply_display_shift(self.datetime_at(rtc_offset)?, self.display_shift)
+    }
 
-                    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
-                        formatter.write_str("`base_date` or `rtc_offset`")
-                    }
+    /// Calculates the date and time returned by reads at the point the RTC reads `rtc_offset`.
+    ///
+    /// This is the same as [`Clock::shifted_datetime_at()`], except that the date and time read
+    /// without communicating with the RTC is returned instead if there is one.
+    fn displayed_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<PrimitiveDateTime, Error> {
+        self.shifted_detached()
+            .unwrap_or_else(|| self.shifted_datetime_at(rtc_offset))
+    }
 
-                    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
-                    where
-                        E: de::Error,
-                    {
-                        match value {
-                            0 => Ok(Field::BaseDate),
-                            1 => Ok(Field::RtcOffset),
-                            _ => Err(de::Error::invalid_value(Unexpected::Unsigned(value), &self)),
-                        }
-                    }
+    /// Reads the currently stored date and time, reusing a buffer stored within the `Clock`.
+    ///
+    /// This returns the same value as [`Clock::read_datetime()`], but the raw bytes received from
+    /// the RTC are held in the `Clock` itself rather than in a new buffer on the stack. This can
+    /// reduce stack usage in builds where IWRAM is tight, at the cost of requiring a mutable
+    /// borrow of the `Clock` for every read.
+    pub fn read_datetime_mut(&mut self) -> Result<PrimitiveDateTime, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return datetime;
+        }
+        if let Some(software_offset) = self.software_offset {
+            return self.shifted_datetime_at(software_offset);
+        }
+        let hour_mode = self.hour_mode;
+        let retries = self.retries;
+        let read_buffer = &mut self.read_buffer;
+        let rtc_offset = fix_hour_mode(
+            self.auto_fix_hour_mode,
+            || {
+                retry(retries, || {
+                    try_read_datetime_offset_into(hour_mode, read_buffer)
+                })
+            },
+            || set_status(Status::HOUR_24),
+        )?;
+        self.shifted_datetime_at(rtc_offset)
+    }
 
-                    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-                    where
-                        E: de::Error,
-                    {
-                        match value {
-                            "base_date" => Ok(Field::BaseDate),
-                            "rtc_offset" => Ok(Field::RtcOffset),
-                            _ => Err(de::Error::unknown_field(value, FIELDS)),
-                        }
-                    }
+    /// Reads the currently stored date and time, allowing interrupts to be serviced during the
+    /// read.
+    ///
+    /// This returns the same value as [`Clock::read_datetime()`]. Normally, interrupts are disabled
+    /// for the entire time the RTC's seven date and time bytes are being received, which can delay
+    /// time-sensitive interrupt handlers, such as those driving audio. This method instead restores
+    /// the previous interrupt enable value briefly after each byte, limiting the time interrupts
+    /// are disabled to the duration of a single byte.
+    ///
+    /// The tradeoff is that the read takes longer overall, as any pending interrupt handlers run
+    /// partway through it, and any interrupt handler that attempts to use the `Clock` during the
+    /// read will receive [`Error::Busy`]. Whether the RTC holds its values while a read is stalled
+    /// by an interrupt handler has not been verified on hardware. If it does not, a read that is
+    /// stalled across a tick of the RTC may combine bytes from before and after the tick. Prefer
+    /// [`Clock::read_datetime()`] unless interrupt latency is a problem.
+    pub fn read_datetime_interruptible(&self) -> Result<PrimitiveDateTime, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return datetime;
+        }
+        let rtc_offset = self
+            .rtc_value(|| self.retry(|| try_read_datetime_offset_interruptible(self.hour_mode)))?;
+        self.shifted_datetime_at(rtc_offset)
+    }
 
-                    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
-                    where
-                        E: de::Error,
-                    {
-                        match value {
-                            b"base_date" => Ok(Field::BaseDate),
-                            b"rtc_offset" => Ok(Field::RtcOffset),
-                            _ => {
-                                let utf8_value =
-                                    str::from_utf8(value).unwrap_or("\u{fffd}\u{fffd}\u{fffd}");
-                                Err(de::Error::unknown_field(utf8_value, FIELDS))
-                            }
-                        }
-                    }
-                }
+    /// Reads the currently stored date and time, returning the date alone if the time is corrupt.
+    ///
+    /// If every value read from the RTC is valid, this returns the same date and time as
+    /// [`Clock::read_datetime()`], with the time wrapped in `Some`. If the date is valid but the
+    /// RTC's hour, minute, or second contains an invalid value, the date is still returned along
+    /// with a time of `None`. This allows a best-effort display of the date when a read is
+    /// partially corrupt.
+    ///
+    /// When the time is `None`, the date is calculated as though the RTC's time were midnight, with
+    /// the display shift then applied. Depending on the stored time, the returned date may
+    /// therefore differ from the true stored date by one day.
+    ///
+    /// An invalid value in the RTC's date still results in an error, as do errors indicating the
+    /// RTC is in an unusable state, such as [`Error::TestMode`] or [`Error::AmPmBitPresent`].
+    pub fn read_datetime_lenient(&self) -> Result<(Date, Option<Time>), Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            let datetime = datetime?;
+            return Ok((datetime.date(), Some(datetime.time())));
+        }
+        let (rtc_offset, time_valid) = match self.software_offset {
+            Some(software_offset) => (software_offset, true),
+            None => self.retry(|| try_read_datetime_offset_lenient(self.hour_mode))?,
+        };
+        let datetime = self.shifted_datetime_at(rtc_offset)?;
+        Ok((datetime.date(), time_valid.then_some(datetime.time())))
+    }
 
-                deserializer.deserialize_identifier(FieldVisitor)
-            }
+    /// Reads the raw bytes of the RTC's date and time, exactly as they are returned by the RTC.
+    ///
+    /// The returned bytes are the year, month, day, weekday, hour, minute, and second, in that
+    /// order, each encoded in binary coded decimal. No validation or decoding is performed, so
+    /// this succeeds even if the bytes are invalid. This is useful for diagnosing corrupt reads.
+    ///
+    /// Note that these are the RTC's own values, not the date and time stored by this `Clock`.
+    pub fn read_raw_bytes(&self) -> Result<[u8; 7], Error> {
+        self.require_rtc()?;
+        self.retry(try_read_datetime_bytes)
+    }
+
+    /// Reads the RTC's raw value, as the number of seconds elapsed since 2000-01-01 00:00:00 on
+    /// the RTC.
+    ///
+    /// This is the value compared against the result of [`Clock::offset_for()`]. Note that this is
+    /// the RTC's own value, not the date and time stored by this `Clock`. For a software `Clock`
+    /// (see [`Clock::new_software()`]), the simulated RTC value is returned.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn read_raw_offset(&self) -> Result<u32, Error> {
+        Ok(self.read_offset()?.get())
+    }
+
+    /// Reads the currently stored date and time, along with the RTC's raw value at the moment of
+    /// reading.
+    ///
+    /// The returned date and time is the same as would be returned by [`Clock::read_datetime()`].
+    /// The raw value records when the reading was taken, so that its age in seconds can later be
+    /// determined by subtracting it from the result of [`Clock::offset_now()`]. This is useful
+    /// when a reading is taken in an interrupt handler and consumed later.
+    ///
+    /// The RTC's value wraps around to zero after 2099-12-31 23:59:59. If it wraps between the
+    /// two reads, the later value will be less than the recorded value and a plain subtraction
+    /// will underflow.
+    pub fn read_timed(&self) -> Result<(PrimitiveDateTime, u32), Error> {
+        let rtc_offset = self.read_offset()?;
+        self.timed_at(rtc_offset)
+    }
+
+    /// Calculates the value returned by [`Clock::read_timed()`] at the point the RTC reads
+    /// `rtc_offset`.
+    fn timed_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<(PrimitiveDateTime, u32), Error> {
+        Ok((self.displayed_at(rtc_offset)?, rtc_offset.get()))
+    }
+
+    /// Reads the RTC's current raw value, for determining the age of a reading taken with
+    /// [`Clock::read_timed()`].
+    ///
+    /// This is the same value as returned by [`Clock::read_raw_offset()`].
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn offset_now(&self) -> Result<u32, Error> {
+        self.read_raw_offset()
+    }
+
+    /// Moves the stored date and time by `by`, for testing code that depends on the passage of
+    /// time.
+    ///
+    /// This is only available when the `host-stub` feature is enabled. The stubbed RTC never
+    /// advances on its own, so this allows tests to simulate time passing, such as advancing past
+    /// midnight to check that a "new day" handler runs. `by` may be negative to move the stored
+    /// date and time backward. Any sub-second component of `by` is discarded.
+    ///
+    /// As the `host-stub` feature can't be enabled when building for the GBA, this is strictly a
+    /// testing utility.
+    ///
+    /// # Panics
+    /// Panics if `by` is longer than the RTC's full range of 100 years in either direction, if the
+    /// resulting date and time can't be represented, or if the stubbed RTC can't be read because
+    /// the GPIO port has been disabled.
+    #[cfg(feature = "host-stub")]
+    pub fn test_advance(&mut self, by: Duration) {
+        assert!(
+            by.whole_seconds().unsigned_abs() <= u64::from(RTC_MAX_SECONDS),
+            "`by` must be within the RTC's range"
+        );
+        let rtc_offset = self
+            .read_offset()
+            .expect("the stubbed RTC must be readable");
+        // Re-base the stored date and time, so that moving backward past midnight on the stored
+        // base date moves the base date as well.
+        let datetime = self
+            .datetime_at(rtc_offset)
+            .ok()
+            .and_then(|datetime| datetime.checked_add(Duration::seconds(by.whole_seconds())))
+            .expect("the advanced date and time must be representable");
+        self.base_date = datetime.date();
+        self.rtc_offset = rtc_offset - datetime.time().into();
+    }
+
+    /// Returns whether reading the stored date and time would return [`Error::Overflow`].
+    ///
+    /// Only the RTC's raw value is read, as by [`Clock::offset_now()`]. The number of seconds
+    /// elapsed since the stored offset is then compared against the range remaining between
+    /// midnight on the stored base date and [`Clock::MAX_DATETIME`], without constructing the
+    /// date and time itself. This allows the stored date and time to be re-based before reads
+    /// begin failing.
+    ///
+    /// Note that dates after 2099 can still be stored and read, as only the RTC's own value is
+    /// limited to that range. Overflow is therefore only possible when the stored date is near the
+    /// end of the range of [`Date`]. The display shift is not taken into account.
+    pub fn would_overflow(&self) -> Result<bool, Error> {
+        let rtc_offset = self.read_offset()?;
+        Ok(self.would_overflow_at(rtc_offset))
+    }
+
+    /// Returns whether calculating the stored date and time at the point the RTC reads
+    /// `rtc_offset` would overflow.
+    fn would_overflow_at(&self, rtc_offset: RtcDateTimeOffset) -> bool {
+        let (elapsed, _) = self.delta_at(rtc_offset);
+        Self::MAX_DATETIME - self.base_date.midnight() < Duration::from(elapsed)
+    }
+
+    /// Moves the stored date and time forward by `seconds`, without wrapping.
+    ///
+    /// This is a software-only adjustment: the RTC itself is not written. The stored date and time
+    /// is tracked as the RTC value corresponding to the start of the stored base date, and moving
+    /// forward lowers that value. Rather than wrapping around past the start of the RTC's range,
+    /// as the RTC's own counter does, the value is clamped at zero using
+    /// [`RtcDateTimeOffset::saturating_sub()`]. If clamped, the stored date and time is moved
+    /// forward by less than `seconds`.
+    ///
+    /// Values of `seconds` beyond the RTC's full range of 100 years are treated as the full range.
+    /// This has no effect on the date and time captured by [`Clock::pause()`].
+    pub fn advance_saturating(&mut self, seconds: u32) {
+        let seconds = RtcDateTimeOffset(
+            RangedU32::new(seconds.min(RTC_MAX_SECONDS)).unwrap_or(RangedU32::MAX),
+        );
+        self.rtc_offset = self.rtc_offset.saturating_sub(seconds);
+    }
+
+    /// Calculates the raw value the RTC will hold when the stored date and time is `datetime`.
+    ///
+    /// This is the inverse of reading the stored date and time: at the moment
+    /// [`Clock::read_raw_offset()`] returns the value returned by this method,
+    /// [`Clock::read_datetime()`] returns `datetime`. Comparing raw values each frame avoids
+    /// reconstructing a date and time on every read, which is useful for cheaply checking
+    /// whether a scheduled event has been reached.
+    ///
+    /// The RTC's value wraps around to zero after the end of 2099. If the returned value is less
+    /// than the current raw value, the RTC will wrap before reaching `datetime`, so a plain `>=`
+    /// comparison is only correct while both values are on the same side of the wrap.
+    ///
+    /// Any sub-second component of `datetime` is discarded. If `datetime` is before midnight on
+    /// the stored base date, or is more than the RTC's full range of 100 years after it,
+    /// [`Error::DateOutOfRange`] is returned.
+    pub fn offset_for(&self, datetime: PrimitiveDateTime) -> Result<u32, Error> {
+        let elapsed = datetime - self.base_date.midnight();
+        if elapsed.is_negative() || elapsed.whole_seconds() > i64::from(RTC_MAX_SECONDS) {
+            return Err(Error::DateOutOfRange);
         }
+        let elapsed = elapsed.whole_seconds() as u32;
 
-        struct ClockVisitor;
+        // Wrap around to the start of the RTC's range if necessary.
+        let remaining = RTC_MAX_SECONDS - self.rtc_offset.get();
+        Ok(if elapsed > remaining {
+            elapsed - remaining - 1
+        } else {
+            self.rtc_offset.get() + elapsed
+        })
+    }
 
-        impl<'de> Visitor<'de> for ClockVisitor {
-            type Value = Clock;
+    /// Calculates the stored date and time at the point the RTC reads `rtc_offset`.
+    fn datetime_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<PrimitiveDateTime, Error> {
+        let (elapsed, _) = self.delta_at(rtc_offset);
 
-            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
-                formatter.write_str("struct Clock")
-            }
+        self.base_date
+            .midnight()
+            .checked_add(elapsed.into())
+            .ok_or(Error::Overflow)
+    }
 
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: SeqAccess<'de>,
-            {
-                let base_date = seq
-                    .next_element()?
-                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                let rtc_offset = seq
-                    .next_element()?
-                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-                Ok(Clock {
-                    base_date,
-                    rtc_offset,
-                })
-            }
+    /// Calculates the number of seconds elapsed on the RTC between the stored RTC offset and the
+    /// point the RTC reads `rtc_offset`.
+    ///
+    /// Also returns whether the RTC wrapped around past the end of 2099 in between.
+    fn delta_at(&self, rtc_offset: RtcDateTimeOffset) -> (RtcDateTimeOffset, bool) {
+        if rtc_offset.0 >= self.rtc_offset.0 {
+            (
+                RtcDateTimeOffset(unsafe { rtc_offset.0.unchecked_sub(self.rtc_offset.0.get()) }),
+                false,
+            )
+        } else {
+            (
+                RtcDateTimeOffset(unsafe {
+                    RangedU32::MAX
+                        .unchecked_sub(self.rtc_offset.0.get())
+                        .unchecked_add(rtc_offset.0.get())
+                        .unchecked_add(1)
+                }),
+                true,
+            )
+        }
+    }
 
-            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
-            where
-                A: MapAccess<'de>,
-            {
-                let mut base_date = None;
-                let mut rtc_offset = None;
+    /// Reads the number of seconds elapsed on the RTC since the stored RTC offset, for debugging.
+    ///
+    /// This is the raw value from which [`Clock::read_datetime()`] calculates the stored date and
+    /// time by adding it to the base date. The returned `bool` is `true` if the RTC's value has
+    /// wrapped around past the end of 2099 since the stored RTC offset, meaning the elapsed
+    /// seconds were calculated across the wrap.
+    ///
+    /// Note that the stored RTC offset is not necessarily the RTC's value at the moment the date
+    /// and time was written, as it is adjusted to account for the time of day written.
+    pub fn read_delta(&self) -> Result<(u32, bool), Error> {
+        let rtc_offset = self.read_offset()?;
+        let (elapsed, wrapped) = self.delta_at(rtc_offset);
+        Ok((elapsed.get(), wrapped))
+    }
 
-                while let Some(field) = map.next_key()? {
-                    match field {
-                        Field::BaseDate => {
-                            if base_date.is_some() {
-                                return Err(de::Error::duplicate_field("base_date"));
-                            }
-                            base_date = Some(map.next_value()?);
-                        }
-                        Field::RtcOffset => {
-                            if rtc_offset.is_some() {
-                                return Err(de::Error::duplicate_field("rtc_offset"));
-                            }
-                            rtc_offset = Some(map.next_value()?);
-                        }
-                    }
-                }
+    /// Returns the amount of time elapsed on the RTC since the stored RTC offset, as a
+    /// [`core::time::Duration`].
+    ///
+    /// This is the same value as returned by [`Clock::read_delta()`], and is useful for consumers
+    /// that depend only on `core` rather than on the [`time`] crate's `Duration`. If the RTC's
+    /// value has wrapped around past the end of 2099 since the stored RTC offset, the elapsed time
+    /// is calculated across the wrap.
+    ///
+    /// Note that the stored RTC offset corresponds to midnight on the stored base date, not to the
+    /// moment the date and time was written. The returned duration is therefore the stored time
+    /// of day plus any whole days elapsed since the base date.
+    pub fn elapsed_core(&self) -> Result<core::time::Duration, Error> {
+        let rtc_offset = self.read_offset()?;
+        Ok(self.elapsed_core_at(rtc_offset))
+    }
 
-                Ok(Clock {
-                    base_date: base_date.ok_or_else(|| de::Error::missing_field("base_date"))?,
-                    rtc_offset: rtc_offset.ok_or_else(|| de::Error::missing_field("rtc_offset"))?,
-                })
-            }
+    /// Calculates the time elapsed since the stored RTC offset at the point the RTC reads
+    /// `rtc_offset`.
+    fn elapsed_core_at(&self, rtc_offset: RtcDateTimeOffset) -> core::time::Duration {
+        let (elapsed, _) = self.delta_at(rtc_offset);
+        elapsed.into()
+    }
+
+    /// Returns whether the currently stored date and time is strictly after `when`.
+    ///
+    /// This is `false` at the exact instant `when`.
+    pub fn is_after(&self, when: PrimitiveDateTime) -> Result<bool, Error> {
+        Ok(self.read_datetime()? > when)
+    }
+
+    /// Returns whether the currently stored date and time is strictly before `when`.
+    ///
+    /// This is `false` at the exact instant `when`.
+    pub fn is_before(&self, when: PrimitiveDateTime) -> Result<bool, Error> {
+        Ok(self.read_datetime()? < when)
+    }
+
+    /// Returns whether the currently stored date and time is within the range `start..end`.
+    ///
+    /// The range is inclusive of `start` and exclusive of `end`, meaning this is `true` at the
+    /// exact instant `start` and `false` at the exact instant `end`. If `end` is not after `start`,
+    /// this is always `false`.
+    pub fn is_between(
+        &self,
+        start: PrimitiveDateTime,
+        end: PrimitiveDateTime,
+    ) -> Result<bool, Error> {
+        Ok((start..end).contains(&self.read_datetime()?))
+    }
+
+    /// Returns the ordering of the currently stored date and time relative to `other`.
+    ///
+    /// This is equivalent to comparing the result of [`Clock::read_datetime()`] against `other`.
+    pub fn compare_to(&self, other: PrimitiveDateTime) -> Result<Ordering, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return Ok(datetime?.cmp(&other));
         }
+        let rtc_offset = self.read_offset()?;
+        self.compare_at(rtc_offset, other)
+    }
 
-        const FIELDS: &[&str] = &["base_date", "rtc_offset"];
-        let result = deserializer.deserialize_struct("Clock", FIELDS, ClockVisitor);
-        if result.is_ok() {
-            // Enable operations with the RTC via General Purpose I/O (GPIO).
-            enable();
-            set_status(Status::HOUR_24).map_err(|error| {
-                de::Error::custom(format_args!(
-                    "could not set RTC status 24 hour bit: {}",
-                    error
-                ))
-            })?;
-            // If the power bit is active, the clock is unreadable.
-            let status = try_read_status().map_err(|error| {
-                de::Error::custom(format_args!("could not read RTC status: {}", error))
-            })?;
-            if status.contains(&Status::POWER) {
-                return Err(de::Error::custom(
-                    "RTC power bit is set, RTC needs to be reset",
-                ));
-            }
-            // If we are in test mode, the clock is unreadable.
-            if is_test_mode().map_err(|error| {
-                de::Error::custom(format_args!(
-                    "could not detect if RTC is in test mode: {}",
-                    error
-                ))
-            })? {
-                return Err(de::Error::custom("RTC is in test mode and must be reset"));
+    /// Returns the ordering of the stored date and time at the point the RTC reads `rtc_offset`
+    /// relative to `other`.
+    fn compare_at(
+        &self,
+        rtc_offset: RtcDateTimeOffset,
+        other: PrimitiveDateTime,
+    ) -> Result<Ordering, Error> {
+        Ok(self.shifted_datetime_at(rtc_offset)?.cmp(&other))
+    }
+
+    /// Returns whether the currently stored date and time is earlier than `last_seen`.
+    ///
+    /// This is intended for detecting when the RTC has been rewound, such as by a player changing
+    /// their console's clock to replay time-gated content. `last_seen` should be a previously read
+    /// date and time that was persisted, such as in SRAM. Reading the exact same date and time is
+    /// not considered a rollback.
+    pub fn detect_rollback(&self, last_seen: PrimitiveDateTime) -> Result<bool, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return Ok(datetime? < last_seen);
+        }
+        let rtc_offset = self.read_offset()?;
+        self.detect_rollback_at(rtc_offset, last_seen)
+    }
+
+    /// Returns whether the stored date and time at the point the RTC reads `rtc_offset` is earlier
+    /// than `last_seen`.
+    fn detect_rollback_at(
+        &self,
+        rtc_offset: RtcDateTimeOffset,
+        last_seen: PrimitiveDateTime,
+    ) -> Result<bool, Error> {
+        Ok(self.compare_at(rtc_offset, last_seen)? == Ordering::Less)
+    }
+
+    /// Returns the indices of all `alarms` that are at or before the currently stored date and
+    /// time.
+    ///
+    /// The date and time is read only once, and every alarm is compared against that single
+    /// reading. This allows polling many scheduled events each frame with a single read from the
+    /// RTC.
+    ///
+    /// Note that this is edge-agnostic: an alarm is yielded on every call after its time has
+    /// passed, not only on the first. Callers must track which alarms have already fired.
+    pub fn alarms_due<'a>(
+        &self,
+        alarms: &'a [PrimitiveDateTime],
+    ) -> Result<impl Iterator<Item = usize> + 'a, Error> {
+        let now = self.read_datetime()?;
+        Ok(alarms
+            .iter()
+            .enumerate()
+            .filter(move |(_, alarm)| **alarm <= now)
+            .map(|(index, _)| index))
+    }
+
+    /// Reads the currently stored date and time as its individual fields.
+    ///
+    /// This is equivalent to decomposing the result of [`Clock::read_datetime()`], along with the
+    /// computed day of the week.
+    pub fn read_components(&self) -> Result<DateTimeComponents, Error> {
+        self.read_datetime()?.try_into()
+    }
+
+    /// Reads the currently stored date and time along with the RTC's [`Status`].
+    ///
+    /// Both are read within a single period of disabled interrupts, meaning the returned status
+    /// reflects the same moment as the returned date and time. This is cheaper than reading each
+    /// separately, and can be used to check whether the read date and time are trustworthy.
+    pub fn read_datetime_and_status(&self) -> Result<(PrimitiveDateTime, Status), Error> {
+        self.require_rtc()?;
+        let (rtc_offset, status) =
+            self.retry(|| try_read_datetime_offset_and_status(self.hour_mode))?;
+        Ok((self.displayed_at(rtc_offset)?, status))
+    }
+
+    /// Reads the currently stored date and time, failing if the RTC reports a power failure.
+    ///
+    /// The RTC's [`Status`] is read along with the date and time within a single period of
+    /// disabled interrupts, just as in [`Clock::read_datetime_and_status()`]. If the status
+    /// indicates the RTC has lost power, [`Error::PowerFailure`] is returned rather than a date and
+    /// time that is likely garbage. This is the read-time equivalent of the power check performed
+    /// when constructing a `Clock`.
+    pub fn read_datetime_guarded(&self) -> Result<PrimitiveDateTime, Error> {
+        self.require_rtc()?;
+        let (rtc_offset, status) =
+            self.retry(|| try_read_datetime_offset_and_status(self.hour_mode))?;
+        self.guarded_at(rtc_offset, status)
+    }
+
+    /// Calculates the stored date and time at the point the RTC reads `rtc_offset`, unless
+    /// `status` indicates the RTC has lost power.
+    fn guarded_at(
+        &self,
+        rtc_offset: RtcDateTimeOffset,
+        status: Status,
+    ) -> Result<PrimitiveDateTime, Error> {
+        if status.power_failure() {
+            return Err(Error::PowerFailure);
+        }
+        self.displayed_at(rtc_offset)
+    }
+
+    /// Returns whether the RTC's periodic frequency interrupt is enabled.
+    ///
+    /// This reads the RTC's status register and checks bit 1 (`INTFE`), as reported by
+    /// [`Status::frequency_output()`]. An RTC left in this mode may have been misconfigured, so
+    /// this can be checked before trusting the date and time it reports. Unless the `read-only`
+    /// feature is enabled, the mode can be turned off using `Clock::set_interrupt_mode()`.
+    pub fn is_frequency_mode(&self) -> Result<bool, Error> {
+        self.require_rtc()?;
+        Ok(self.retry(try_read_status)?.frequency_output())
+    }
+
+    /// Returns whether the RTC actually operates in 24-hour mode.
+    ///
+    /// Some RTC clones accept the 24-hour bit in the status register without honoring it,
+    /// continuing to report hours in 12-hour form. This reads the status register back and, if it
+    /// reports 24-hour mode, samples the current hour to confirm that it is not reported with a PM
+    /// flag. Hours before noon are reported identically in both modes, so such an RTC can only be
+    /// detected while its hour is in the afternoon; before then, this returns `true`.
+    pub fn supports_24_hour(&self) -> Result<bool, Error> {
+        self.require_rtc()?;
+        self.retry(supports_24_hour)
+    }
+
+    /// Returns whether the RTC is currently in test mode.
+    ///
+    /// While in test mode, the RTC's time cannot be read, and reads fail with
+    /// [`Error::TestMode`]. Creating a new `Clock` with [`Clock::new()`] resets the RTC, taking it
+    /// out of test mode.
+    pub fn in_test_mode(&self) -> Result<bool, Error> {
+        self.require_rtc()?;
+        self.retry(is_test_mode)
+    }
+
+    /// Reads the currently stored date and time, recovering the RTC if it is in an unusable state.
+    ///
+    /// If the read fails with [`Error::PowerFailure`], [`Error::TestMode`], or
+    /// [`Error::NotEnabled`], the RTC is recovered using [`Clock::recover()`] and the date and time
+    /// are read again. **Note that a recovery discards the previously stored date and time,
+    /// replacing it with `fallback`.** Any other errors are returned without attempting recovery.
+    pub fn read_datetime_or_reset(
+        &mut self,
+        fallback: PrimitiveDateTime,
+    ) -> Result<PrimitiveDateTime, Error> {
+        match self.read_datetime() {
+            Err(Error::PowerFailure | Error::TestMode | Error::NotEnabled) => {
+                self.recover(fallback)?;
+                self.read_datetime()
             }
+            result => result,
         }
-        result
     }
-}
 
-#[cfg(test)]
-#[no_mangle]
-pub fn main() {
-    let _ = mgba_log::init();
-    test_harness()
-}
+    /// Reinitializes the RTC and sets the stored date and time to `datetime`.
+    ///
+    /// This performs the same initialization sequence as [`Clock::new()`], including resetting
+    /// the RTC if necessary, while preserving this `Clock`'s configuration, including its display
+    /// shift. The previously stored date and time are discarded.
+    pub fn recover(&mut self, datetime: PrimitiveDateTime) -> Result<(), Error> {
+        self.require_rtc()?;
+        let auto_fix_hour_mode = self.auto_fix_hour_mode;
+        let display_shift = self.display_shift;
+        *self = ClockBuilder::from_clock(self).build(datetime)?;
+        self.auto_fix_hour_mode = auto_fix_hour_mode;
+        self.display_shift = display_shift;
+        Ok(())
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::{
-        gpio,
-        Clock,
-        Error,
-    };
-    use crate::date_time::RtcDateTimeOffset;
-    use claims::{
-        assert_err_eq,
-        assert_ok,
-        assert_ok_eq,
-    };
-    use deranged::RangedU32;
-    use gba_test::test;
-    use time_macros::{
-        date,
-        datetime,
-        time,
-    };
+    /// Captures the currently stored date and time as a [`Snapshot`].
+    ///
+    /// The returned `Snapshot` also stores the raw RTC value it was read from, allowing the time
+    /// elapsed between snapshots to be calculated independently of the stored date and time.
+    pub fn snapshot(&self) -> Result<Snapshot, Error> {
+        let rtc_offset = self.read_offset()?;
+        Ok(Snapshot {
+            datetime: self.displayed_at(rtc_offset)?,
+            rtc_offset,
+        })
+    }
+
+    /// Returns the approximate number of frames elapsed since `snapshot` was captured.
+    ///
+    /// This is calculated as the number of seconds elapsed on the RTC multiplied by 60, the
+    /// approximate number of frames displayed by the GBA each second.
+    ///
+    /// **Note that the RTC only has a resolution of one second.** The returned value therefore
+    /// always increases in increments of 60 frames, and does not account for how far into the
+    /// current second either reading was taken. It should not be used for frame-accurate timing.
+    pub fn frames_since(&self, snapshot: &Snapshot) -> Result<u64, Error> {
+        let rtc_offset = self.read_offset()?;
+        Ok(snapshot.frames_until(rtc_offset))
+    }
+
+    /// Reads the currently stored date and time as a Unix timestamp.
+    ///
+    /// The returned value is the number of seconds elapsed since 1970-01-01 00:00:00 UTC. The
+    /// stored date and time are interpreted as being at the configured UTC offset (see
+    /// [`Clock::set_utc_offset()`]), which is UTC by default.
+    ///
+    /// The display shift set using [`Clock::set_display_shift()`] is not applied.
+    pub fn read_unix_timestamp(&self) -> Result<i64, Error> {
+        let rtc_offset = self.read_offset()?;
+        Ok(self
+            .datetime_at(rtc_offset)?
+            .assume_offset(self.utc_offset)
+            .unix_timestamp())
+    }
+
+    /// Reads the currently stored date and time as an [`OffsetDateTime`] in UTC.
+    ///
+    /// This assumes the stored date and time is in UTC, and simply attaches [`UtcOffset::UTC`] to
+    /// it. The configured UTC offset (see [`Clock::set_utc_offset()`]) is ignored.
+    ///
+    /// As with [`Clock::read_unix_timestamp()`], the display shift set using
+    /// [`Clock::set_display_shift()`] is not applied.
+    pub fn read_utc(&self) -> Result<OffsetDateTime, Error> {
+        let rtc_offset = self.read_offset()?;
+        self.utc_at(rtc_offset)
+    }
+
+    /// Calculates the stored date and time in UTC at the point the RTC reads `rtc_offset`.
+    fn utc_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<OffsetDateTime, Error> {
+        self.datetime_at(rtc_offset)
+            .map(PrimitiveDateTime::assume_utc)
+    }
+
+    /// Writes a new date and time.
+    ///
+    /// Note that this does not actually change the stored date and time in the RTC itself. While
+    /// RTC values are writable on real hardware, they are often not writable in GBA emulators.
+    /// Therefore, the date and time are stored as being offset from the current RTC date and time
+    /// to maintain maximum compatibility.
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_datetime(&mut self, datetime: PrimitiveDateTime) -> Result<(), Error> {
+        let rtc_offset = self.rtc_value(|| try_read_datetime_offset(self.hour_mode))?;
+        self.base_date = datetime.date();
+        self.rtc_offset = rtc_offset - datetime.time().into();
+        Ok(())
+    }
+
+    /// Writes a new date and time, then reads it back to verify that it was stored correctly.
+    ///
+    /// The read back date and time may be up to one second later than `datetime`, as the RTC may
+    /// tick between the write and the read. If it differs by any other amount,
+    /// [`Error::VerifyFailed`] is returned. The new date and time remains stored either way.
+    ///
+    /// As with [`Clock::write_datetime()`], the sub-second component of `datetime` is discarded
+    /// before comparing. The display shift set using [`Clock::set_display_shift()`] is not
+    /// applied to the value read back.
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_datetime_verified(&mut self, datetime: PrimitiveDateTime) -> Result<(), Error> {
+        self.write_datetime(datetime)?;
+        let rtc_offset = self.read_offset()?;
+        self.verify_at(datetime, rtc_offset)
+    }
+
+    /// Checks that the stored date and time at the point the RTC reads `rtc_offset` is at most one
+    /// second after `datetime`, ignoring the sub-second component of `datetime`.
+    #[cfg(not(feature = "read-only"))]
+    fn verify_at(
+        &self,
+        datetime: PrimitiveDateTime,
+        rtc_offset: RtcDateTimeOffset,
+    ) -> Result<(), Error> {
+        let difference = self.datetime_at(rtc_offset)? - datetime.replace_nanosecond(0)?;
+        if (Duration::ZERO..=Duration::SECOND).contains(&difference) {
+            Ok(())
+        } else {
+            Err(Error::VerifyFailed)
+        }
+    }
+
+    /// Writes a new date and time from a Unix timestamp.
+    ///
+    /// The timestamp is interpreted as the number of seconds elapsed since 1970-01-01 00:00:00
+    /// UTC, and the resulting date and time are stored at the configured UTC offset (see
+    /// [`Clock::set_utc_offset()`]), which is UTC by default.
+    ///
+    /// Only timestamps resulting in a date within the years 2000-2099 are accepted. Timestamps
+    /// outside of this range will return [`Error::DateOutOfRange`].
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_unix_timestamp(&mut self, timestamp: i64) -> Result<(), Error> {
+        let local_timestamp = timestamp
+            .checked_add(self.utc_offset.whole_seconds().into())
+            .ok_or(Error::DateOutOfRange)?;
+        let datetime = OffsetDateTime::from_unix_timestamp(local_timestamp)
+            .map_err(|_| Error::DateOutOfRange)?;
+        validate_year(datetime.year())?;
+        self.write_datetime(PrimitiveDateTime::new(datetime.date(), datetime.time()))
+    }
+
+    /// Re-anchors the stored date and time to the current RTC value.
+    ///
+    /// The stored date and time are not changed. Instead, the base date and RTC offset from which
+    /// the current date and time are calculated are updated to the current moment, as if the
+    /// current date and time had been written using `Clock::write_datetime()`. This is done using
+    /// only a single read from the RTC, meaning no time is lost between reading and writing.
+    pub fn sync_offset(&mut self) -> Result<(), Error> {
+        let rtc_offset = self.rtc_value(|| try_read_datetime_offset(self.hour_mode))?;
+        self.rebase_at(rtc_offset)
+    }
+
+    /// Re-anchors the stored date and time to the point the RTC reads `rtc_offset`.
+    fn rebase_at(&mut self, rtc_offset: RtcDateTimeOffset) -> Result<(), Error> {
+        let datetime = self.datetime_at(rtc_offset)?;
+        self.base_date = datetime.date();
+        self.rtc_offset = rtc_offset - datetime.time().into();
+        Ok(())
+    }
+
+    /// Freezes the date and time returned by reads.
+    ///
+    /// The current date and time is read from the RTC and captured. Until [`Clock::resume()`] is
+    /// called, every read of the stored date and time, such as [`Clock::read_datetime()`],
+    /// [`Clock::read_date()`], and [`Clock::read_time()`], returns the captured value. Reads that
+    /// only need the stored date and time do so without reading from the RTC. Reads of the RTC's
+    /// own values and of Unix timestamps, such as [`Clock::read_unix_timestamp()`], are
+    /// unaffected.
+    ///
+    /// Calling this while already paused does nothing.
+    pub fn pause(&mut self) -> Result<(), Error> {
+        if self.paused.is_some() {
+            return Ok(());
+        }
+        let rtc_offset = self.read_offset()?;
+        self.pause_at(rtc_offset)
+    }
+
+    /// Captures the stored date and time at the point the RTC reads `rtc_offset` as the paused
+    /// date and time.
+    fn pause_at(&mut self, rtc_offset: RtcDateTimeOffset) -> Result<(), Error> {
+        self.paused = Some(self.datetime_at(rtc_offset)?);
+        Ok(())
+    }
+
+    /// Unfreezes the date and time returned by reads.
+    ///
+    /// The stored date and time are re-based so that, at the moment of resuming, they equal the
+    /// date and time captured by [`Clock::pause()`], and then continue advancing from there. The
+    /// time spent paused is therefore discarded rather than skipped over: if the `Clock` was
+    /// paused at 12:00:00 and resumed ten minutes later, reads immediately after resuming return
+    /// 12:00:00 rather than 12:10:00. Any date and time written while paused is likewise replaced
+    /// by the captured value.
+    ///
+    /// As the RTC only has a resolution of one second, up to one second may be lost or gained
+    /// when resuming.
+    ///
+    /// Calling this while not paused does nothing.
+    pub fn resume(&mut self) -> Result<(), Error> {
+        if self.paused.is_none() {
+            return Ok(());
+        }
+        let rtc_offset = self.read_offset()?;
+        self.resume_at(rtc_offset);
+        Ok(())
+    }
+
+    /// Re-bases the stored date and time so that the RTC reading `rtc_offset` corresponds to the
+    /// paused date and time, and unpauses.
+    fn resume_at(&mut self, rtc_offset: RtcDateTimeOffset) {
+        if let Some(datetime) = self.paused.take() {
+            self.base_date = datetime.date();
+            self.rtc_offset = rtc_offset - datetime.time().into();
+        }
+    }
+
+    /// Returns whether the `Clock` is paused.
+    ///
+    /// See [`Clock::pause()`].
+    pub fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+
+    /// Advances the simulated RTC value of a software `Clock` by `seconds`.
+    ///
+    /// Just as the RTC's own value does, the simulated value wraps around to zero after the end of
+    /// 2099, which reads of the stored date and time account for as they would for the RTC. As
+    /// with the RTC, the stored date and time therefore cannot advance more than 100 years beyond
+    /// the stored base date without being re-anchored, such as by [`Clock::sync_offset()`].
+    ///
+    /// This has no effect on a `Clock` that reads from the RTC. See [`Clock::new_software()`].
+    pub fn tick(&mut self, seconds: u32) {
+        if let Some(software_offset) = &mut self.software_offset {
+            // SAFETY: The remainder is always less than or equal to `RTC_MAX_SECONDS`.
+            *software_offset += RtcDateTimeOffset(unsafe {
+                RangedU32::new_unchecked(seconds % (RTC_MAX_SECONDS + 1))
+            });
+        }
+    }
+
+    /// Returns whether the `Clock` is a software `Clock`, created with [`Clock::new_software()`].
+    pub fn is_software(&self) -> bool {
+        self.software_offset.is_some()
+    }
+
+    /// Returns the simulated RTC value of a software `Clock`, or performs `read` to read the RTC's
+    /// value otherwise.
+    fn rtc_value<T, F>(&self, read: F) -> Result<T, Error>
+    where
+        T: From<RtcDateTimeOffset>,
+        F: FnOnce() -> Result<T, Error>,
+    {
+        match self.software_offset {
+            Some(software_offset) => Ok(software_offset.into()),
+            None => read(),
+        }
+    }
+
+    /// Reads the RTC's current value, retrying on transient errors, or returns the simulated value
+    /// of a software `Clock`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn read_offset(&self) -> Result<RtcDateTimeOffset, Error> {
+        self.rtc_value(|| self.retry(|| try_read_datetime_offset(self.hour_mode)))
+    }
+
+    /// Returns [`Error::SoftwareClock`] if the `Clock` is a software `Clock`, which has no RTC to
+    /// communicate with.
+    fn require_rtc(&self) -> Result<(), Error> {
+        if self.is_software() {
+            Err(Error::SoftwareClock)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the paused date and time with the display shift applied, if the `Clock` is paused.
+    fn shifted_detached(&self) -> Option<Result<PrimitiveDateTime, Error>> {
+        self.paused
+            .map(|datetime| apply_display_shift(datetime, self.display_shift))
+    }
+
+    /// Returns the paused time with the display shift applied, if the `Clock` is paused.
+    ///
+    /// Unlike [`Clock::shifted_detached()`], the shift wraps around midnight rather than changing
+    /// the date.
+    fn shifted_detached_time(&self) -> Option<Time> {
+        self.paused
+            .map(|datetime| datetime.time() + self.display_shift)
+    }
+
+    /// Reads the currently stored date.
+    ///
+    /// If a display shift has been set using [`Clock::set_display_shift()`], it is applied to the
+    /// returned date.
+    ///
+    /// While the `Clock` is paused (see [`Clock::pause()`]), the date captured when pausing is
+    /// returned without reading from the RTC. A software `Clock` (see [`Clock::new_software()`])
+    /// likewise never reads from the RTC.
+    ///
+    /// The RTC's two-digit year wraps from 99 back to 00 after 2099-12-31 23:59:59. The returned
+    /// date is always calculated from the number of seconds elapsed on the RTC since the stored
+    /// date was set, and a wrap is counted as a single second, so the date steps forward normally
+    /// across the RTC's century boundary rather than jumping by 100 years. This is the same
+    /// calculation used by [`Clock::read_datetime()`].
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn read_date(&self) -> Result<Date, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return datetime.map(PrimitiveDateTime::date);
+        }
+        let rtc_offset = self.read_offset()?;
+        self.shifted_date_at(rtc_offset)
+    }
+
+    /// Calculates the stored date at the point the RTC reads `rtc_offset`, with the display shift
+    /// applied.
+    fn shifted_date_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<Date, Error> {
+        if self.display_shift.is_zero() {
+            self.date_at(rtc_offset)
+        } else {
+            Ok(self.shifted_datetime_at(rtc_offset)?.date())
+        }
+    }
+
+    /// Reads the currently stored date, without applying the display shift.
+    #[cfg(not(feature = "read-only"))]
+    fn read_unshifted_date(&self) -> Result<Date, Error> {
+        let rtc_offset = self.read_offset()?;
+        self.date_at(rtc_offset)
+    }
+
+    /// Returns whether the currently stored date is February 29.
+    pub fn is_leap_day(&self) -> Result<bool, Error> {
+        Ok(is_leap_day(self.read_date()?))
+    }
+
+    /// Reads the ISO year and ISO week number of the currently stored date.
+    ///
+    /// The week number is in the range `1..=53`. Note that the ISO year can differ from the
+    /// calendar year near the start and end of the year: for example, 2000-01-01 falls in week 52
+    /// of ISO year 1999.
+    pub fn read_iso_week(&self) -> Result<(i32, u8), Error> {
+        Ok(iso_week(self.read_date()?))
+    }
+
+    /// Reads the day of the week of the currently stored date as a number in the range `1..=7`.
+    ///
+    /// If `monday_based` is `true`, days are numbered according to the ISO convention, with Monday
+    /// as `1` and Sunday as `7`. Otherwise, Sunday is `1` and Saturday is `7`.
+    pub fn read_weekday_number(&self, monday_based: bool) -> Result<u8, Error> {
+        Ok(weekday_number(self.read_date()?, monday_based))
+    }
+
+    /// Reads the ordinal day of the year of the currently stored date.
+    ///
+    /// The ordinal day is 1-based, starting at `1` on January 1 and ending at `365`, or `366` in
+    /// leap years, on December 31.
+    pub fn read_ordinal(&self) -> Result<u16, Error> {
+        Ok(self.read_date()?.ordinal())
+    }
+
+    /// Calculates the stored date at the point the RTC reads `rtc_offset`.
+    fn date_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<Date, Error> {
+        self.base_date
+            .checked_add((rtc_offset - self.rtc_offset).into())
+            .ok_or(Error::Overflow)
+    }
+
+    /// Writes a new date.
+    ///
+    /// This preserves the stored time.
+    ///
+    /// Note that this does not actually change the stored date in the RTC itself. While RTC values
+    /// are writable on real hardware, they are often not writable in GBA emulators. Therefore, the
+    /// date and time are stored as being offset from the current RTC date and time to maintain
+    /// maximum compatibility.
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_date(&mut self, date: Date) -> Result<(), Error> {
+        let rtc_offset = self.rtc_value(|| try_read_datetime_offset(self.hour_mode))?;
+        self.base_date = date;
+        // Calculate the current time offset.
+        let current_time_offset: RtcTimeOffset = if rtc_offset.0 >= self.rtc_offset.0 {
+            RtcDateTimeOffset(unsafe { rtc_offset.0.unchecked_sub(self.rtc_offset.0.get()) }).into()
+        } else {
+            RtcDateTimeOffset(unsafe {
+                RangedU32::MAX
+                    .unchecked_sub(self.rtc_offset.0.get())
+                    .unchecked_add(rtc_offset.0.get())
+                    .unchecked_add(1)
+            })
+            .into()
+        };
+        self.rtc_offset =
+            RtcDateTimeOffset(unsafe { rtc_offset.0.unchecked_sub(current_time_offset.0.get()) });
+        Ok(())
+    }
+
+    /// Writes a new year.
+    ///
+    /// This preserves the stored month, day, and time. If the stored date is February 29 and
+    /// `year` is not a leap year, the day is clamped to February 28.
+    ///
+    /// Only years within the range 2000-2099 are accepted. Years outside of this range will return
+    /// [`Error::DateOutOfRange`].
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_year(&mut self, year: u16) -> Result<(), Error> {
+        let date = replace_year(self.read_unshifted_date()?, year)?;
+        self.write_date(date)
+    }
+
+    /// Writes a new month.
+    ///
+    /// This preserves the stored year, day, and time. If the stored day does not exist in the new
+    /// month, it is clamped to the last day of the month. For example, writing February when the
+    /// stored date is January 31 will result in February 28 (or February 29 in a leap year).
+    ///
+    /// `month` must be within the range `1..=12`. Other values will return
+    /// [`Error::InvalidMonth`].
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_month(&mut self, month: u8) -> Result<(), Error> {
+        let date = replace_month(self.read_unshifted_date()?, month)?;
+        self.write_date(date)
+    }
+
+    /// Writes a new day of the month.
+    ///
+    /// This preserves the stored year, month, and time. Unlike [`Clock::write_month()`], days are
+    /// not clamped: a day that does not exist in the stored month, such as February 30, is
+    /// rejected with [`Error::InvalidDay`] and the stored date is left unchanged.
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_day(&mut self, day: u8) -> Result<(), Error> {
+        let date = replace_day(self.read_unshifted_date()?, day)?;
+        self.write_date(date)
+    }
+
+    /// Reads the currently stored time.
+    ///
+    /// This is always faster than using [`Clock::read_datetime()`], as it only requires reading
+    /// three bytes from the RTC instead of seven.
+    ///
+    /// As the RTC only has a resolution of one second, the sub-second component of the returned
+    /// `Time` is always zero, even if a time with a nonzero sub-second component was written.
+    ///
+    /// If a display shift has been set using [`Clock::set_display_shift()`], it is applied to the
+    /// returned time, wrapping around midnight if necessary.
+    ///
+    /// While the `Clock` is paused (see [`Clock::pause()`]), the time captured when pausing is
+    /// returned without reading from the RTC. A software `Clock` (see [`Clock::new_software()`])
+    /// likewise never reads from the RTC.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn read_time(&self) -> Result<Time, Error> {
+        if let Some(time) = self.shifted_detached_time() {
+            return Ok(time);
+        }
+        let rtc_time_offset =
+            self.rtc_value(|| self.retry(|| try_read_time_offset(self.hour_mode)))?;
+        let stored_time_offset: RtcTimeOffset = self.rtc_offset.into();
+
+        let time: Time = if rtc_time_offset.0 >= stored_time_offset.0 {
+            RtcTimeOffset(unsafe { rtc_time_offset.0.unchecked_sub(stored_time_offset.0.get()) })
+                .into()
+        } else {
+            RtcTimeOffset(unsafe {
+                RangedU32::MAX
+                    .unchecked_sub(stored_time_offset.0.get())
+                    .unchecked_add(rtc_time_offset.0.get())
+                    .unchecked_add(1)
+            })
+            .into()
+        };
+
+        Ok(time + self.display_shift)
+    }
+
+    /// Reads the number of seconds elapsed since midnight in the currently stored time.
+    ///
+    /// The returned value is in the range `0..=86399`. This reads the same bytes from the RTC as
+    /// [`Clock::read_time()`], but returns the raw number of seconds rather than constructing a
+    /// `Time`.
+    ///
+    /// If a display shift has been set using [`Clock::set_display_shift()`], it is applied to the
+    /// returned value, wrapping around midnight if necessary.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn seconds_of_day(&self) -> Result<u32, Error> {
+        if let Some(time) = self.shifted_detached_time() {
+            return Ok(RtcDateTimeOffset::from(time).get());
+        }
+        let rtc_time_offset =
+            self.rtc_value(|| self.retry(|| try_read_time_offset(self.hour_mode)))?;
+        Ok(self.seconds_of_day_at(rtc_time_offset))
+    }
+
+    /// Calculates the number of seconds since midnight in the stored time at the point the RTC's
+    /// time reads `rtc_time_offset`, with the display shift applied.
+    fn seconds_of_day_at(&self, rtc_time_offset: RtcTimeOffset) -> u32 {
+        let stored_time_offset: RtcTimeOffset = self.rtc_offset.into();
+        let seconds = rtc_time_offset - stored_time_offset;
+        if self.display_shift.is_zero() {
+            seconds.0.get()
+        } else {
+            RtcDateTimeOffset::from(Time::from(seconds) + self.display_shift).get()
+        }
+    }
+
+    /// Reads a value identifying the current minute of the stored date and time.
+    ///
+    /// The returned value is calculated from the same date and time returned by
+    /// [`Clock::read_datetime()`], as the number of whole minutes elapsed since the start of the
+    /// Julian day numbering (see [`Date::to_julian_day()`]). It changes exactly when the minute
+    /// returned by reads changes, and is the same for every read within a minute, making it
+    /// suitable as a key for caching values that only need recalculating once per minute.
+    ///
+    /// As with [`Clock::read_datetime()`], the display shift is applied, and while the `Clock` is
+    /// paused (see [`Clock::pause()`]), the value for the date and time captured when pausing is
+    /// returned without reading from the RTC. Since the value identifies the minute itself, it
+    /// can be compared across changes to the stored date and time.
+    pub fn minute_fingerprint(&self) -> Result<i64, Error> {
+        if let Some(datetime) = self.shifted_detached() {
+            return datetime.map(julian_minute);
+        }
+        let rtc_offset = self.read_offset()?;
+        self.minute_fingerprint_at(rtc_offset)
+    }
+
+    /// Calculates the minute fingerprint at the point the RTC reads `rtc_offset`.
+    fn minute_fingerprint_at(&self, rtc_offset: RtcDateTimeOffset) -> Result<i64, Error> {
+        self.shifted_datetime_at(rtc_offset).map(julian_minute)
+    }
+
+    /// Reads the progress through the current day as a fixed-point fraction.
+    ///
+    /// The returned pair is `(numerator, denominator)`, where the numerator is the number of
+    /// seconds elapsed since midnight (as returned by [`Clock::seconds_of_day()`]) and the
+    /// denominator is always `86400`, the number of seconds in a day. The fraction is therefore in
+    /// the range `0/86400..=86399/86400`, and never reaches `1`. This allows interpolating across
+    /// the day using only integer arithmetic, such as `value * numerator / denominator`, as the GBA
+    /// has no floating-point unit.
+    pub fn day_progress(&self) -> Result<(u32, u32), Error> {
+        if let Some(time) = self.shifted_detached_time() {
+            return Ok((RtcDateTimeOffset::from(time).get(), 86_400));
+        }
+        let rtc_time_offset =
+            self.rtc_value(|| self.retry(|| try_read_time_offset(self.hour_mode)))?;
+        Ok(self.day_progress_at(rtc_time_offset))
+    }
+
+    /// Calculates the progress through the day in the stored time at the point the RTC's time
+    /// reads `rtc_time_offset`.
+    fn day_progress_at(&self, rtc_time_offset: RtcTimeOffset) -> (u32, u32) {
+        (self.seconds_of_day_at(rtc_time_offset), 86_400)
+    }
+
+    /// Replays previously recorded RTC values without reading from the hardware.
+    ///
+    /// `recorded_offsets` are raw RTC values, such as those returned by
+    /// [`Clock::read_raw_offset()`], and midnight on `base_date` is the stored date and time at the
+    /// point the RTC reads `0`. The returned [`ReplayClock`] yields the date and time that
+    /// [`Clock::read_datetime()`] would have returned for each recorded value, in order. This is
+    /// intended for deterministically testing time-dependent logic.
+    #[cfg(feature = "replay")]
+    pub fn replay_from(base_date: Date, recorded_offsets: &[u32]) -> ReplayClock<'_> {
+        ReplayClock::new(base_date, recorded_offsets)
+    }
+
+    /// Returns a future that resolves to the stored time once it advances to the next second.
+    ///
+    /// The future reads the stored time each time it is polled, resolving once the second differs
+    /// from the first poll. **It does not arrange for itself to be woken**, so it must be polled
+    /// frequently by the executor, such as once per VBlank. See [`NextSecond`] for more details.
+    #[cfg(feature = "async")]
+    pub fn next_second(&self) -> NextSecond<'_> {
+        NextSecond::new(self)
+    }
+
+    /// Reads the currently stored time in 12-hour form, for display.
+    ///
+    /// Returns the hour in the range `1..=12`, the minute, the second, and whether the time is PM.
+    /// Midnight is `12` AM and noon is `12` PM. This is purely a conversion of the value returned
+    /// by [`Clock::read_time()`] and does not change the hour mode of the RTC, which always
+    /// operates in 24-hour mode.
+    pub fn read_time_12h(&self) -> Result<(u8, u8, u8, bool), Error> {
+        Ok(time_12h(self.read_time()?))
+    }
+
+    /// Reads the currently stored time, advanced by an approximate fraction of a second.
+    ///
+    /// The RTC only has a resolution of one second. To animate smoothly between its ticks, this
+    /// adds `frames_since_last_tick` sixtieths of a second to the time read from the RTC. The
+    /// caller is responsible for counting frames, resetting the count to zero whenever the seconds
+    /// value returned by [`Clock::read_time()`] changes. Counts of `60` or more are treated as
+    /// `59`, so the returned time never reaches the following second.
+    ///
+    /// Note that this is only a visual approximation, not a real sub-second clock. The GBA's frame
+    /// rate is not exactly 60 frames per second, and the count is not synchronized with the RTC's
+    /// ticks.
+    pub fn interpolated_time(&self, frames_since_last_tick: u8) -> Result<Time, Error> {
+        Ok(interpolate_time(self.read_time()?, frames_since_last_tick))
+    }
+
+    /// Reads the currently stored time and writes it into `buf` as ASCII text.
+    ///
+    /// The time is written in the form `HH:MM:SS`, with each component zero-padded to two digits.
+    /// This avoids [`core::fmt`] entirely, making it suitable for rendering text every frame.
+    /// Nothing is written to `buf` if the read fails.
+    pub fn write_time_ascii(&self, buf: &mut [u8; 8]) -> Result<(), Error> {
+        *buf = format_time_ascii(self.read_time()?);
+        Ok(())
+    }
+
+    /// Reads the currently stored date and writes it into `buf` as ASCII text.
+    ///
+    /// The date is written in the form `YYYY-MM-DD`, with each component zero-padded. This avoids
+    /// [`core::fmt`] entirely, making it suitable for rendering text every frame. Nothing is
+    /// written to `buf` if the read fails.
+    ///
+    /// Only years within the range 0-9999 can be written in this form. Later years will return
+    /// [`Error::Overflow`], and negative years will return [`Error::Underflow`].
+    pub fn write_date_ascii(&self, buf: &mut [u8; 10]) -> Result<(), Error> {
+        *buf = format_date_ascii(self.read_date()?)?;
+        Ok(())
+    }
+
+    /// Returns the amount of time until the next occurrence of `target` within a day.
+    ///
+    /// If `target` is earlier than the currently stored time, this is the amount of time until
+    /// `target` tomorrow. If `target` is exactly the currently stored time, this returns
+    /// [`Duration::ZERO`] rather than a full day.
+    pub fn duration_until_daily(&self, target: Time) -> Result<Duration, Error> {
+        Ok(duration_until_daily(self.read_time()?, target))
+    }
+
+    /// Returns the upcoming midnight, which is the start of the day after the currently stored
+    /// date.
+    ///
+    /// As with [`Clock::read_datetime()`], the stored date is not limited to the years 2000-2099.
+    /// If the stored date is the date of [`Clock::MAX_DATETIME`], the next midnight cannot be
+    /// represented and [`Error::Overflow`] is returned.
+    pub fn next_midnight(&self) -> Result<PrimitiveDateTime, Error> {
+        next_midnight(self.read_date()?)
+    }
+
+    /// Returns the number of seconds remaining until the stored time reaches the next whole
+    /// minute.
+    ///
+    /// The returned value is in the range `1..=60`. When the stored time is exactly on a minute
+    /// boundary (its seconds are zero), this returns `60`, as the next minute is a full minute
+    /// away.
+    pub fn seconds_to_next_minute(&self) -> Result<u8, Error> {
+        Ok(seconds_to_next_minute(self.read_time()?))
+    }
+
+    /// Reads the currently stored hour and minute.
+    ///
+    /// When the stored time, with the display shift applied, is aligned to a whole minute, this
+    /// only requires reading two bytes from the RTC, as the transaction is terminated before the
+    /// seconds are read. Otherwise, the seconds are required to determine the current minute and
+    /// all three time bytes are read, equivalent to [`Clock::read_time()`].
+    pub fn read_hour_minute(&self) -> Result<(u8, u8), Error> {
+        if let Some(time) = self.shifted_detached_time() {
+            return Ok((time.hour(), time.minute()));
+        }
+        let stored_time_offset: RtcTimeOffset = self.rtc_offset.into();
+        // The displayed minute changes at the same moment as the RTC's minute only if the stored
+        // offset less the display shift is a whole number of minutes.
+        let aligned = self.display_shift.subsec_nanoseconds() == 0
+            && (i64::from(stored_time_offset.0.get()) - self.display_shift.whole_seconds())
+                .rem_euclid(60)
+                == 0;
+        let rtc_time_offset = self.rtc_value(|| {
+            if aligned {
+                self.retry(|| try_read_hour_minute_offset(self.hour_mode))
+            } else {
+                self.retry(|| try_read_time_offset(self.hour_mode))
+            }
+        })?;
+
+        let time = Time::from(rtc_time_offset - stored_time_offset) + self.display_shift;
+        Ok((time.hour(), time.minute()))
+    }
+
+    /// Writes a new time.
+    ///
+    /// This preserves the stored date.
+    ///
+    /// Note that this does not actually change the stored time in the RTC itself. While RTC values
+    /// are writable on real hardware, they are often not writable in GBA emulators. Therefore, the
+    /// date and time are stored as being offset from the current RTC date and time to maintain
+    /// maximum compatibility.
+    #[cfg(not(feature = "read-only"))]
+    pub fn write_time(&mut self, time: Time) -> Result<(), Error> {
+        let rtc_time_offset = self.rtc_value(|| try_read_time_offset(self.hour_mode))?;
+        self.write_time_at(rtc_time_offset, time);
+        Ok(())
+    }
+
+    /// Writes a new time as of the point the RTC's time of day reads `rtc_time_offset`.
+    ///
+    /// The stored offset is moved by the difference between the current and new times, which is
+    /// always within a single day. This means the stored date is preserved even when the current
+    /// and new times are on opposite sides of midnight.
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at(&mut self, rtc_time_offset: RtcTimeOffset, time: Time) {
+        let stored_time_offset = RtcTimeOffset::from(self.rtc_offset);
+
+        let current_time: Time = if rtc_time_offset.0 >= stored_time_offset.0 {
+            RtcTimeOffset(unsafe { rtc_time_offset.0.unchecked_sub(stored_time_offset.0.get()) })
+                .into()
+        } else {
+            RtcTimeOffset(unsafe {
+                RangedU32::MAX
+                    .unchecked_sub(stored_time_offset.0.get())
+                    .unchecked_add(rtc_time_offset.0.get())
+                    .unchecked_add(1)
+            })
+            .into()
+        };
+
+        // The RTC has a resolution of one second, so any sub-second component is discarded. This
+        // must happen before calculating the difference, as truncating the difference itself
+        // would round toward the current time.
+        let time = time - Duration::nanoseconds(time.nanosecond().into());
+
+        // This difference will be within ±86,399. It can therefore fit within an i32.
+        let delta = (current_time - time).whole_seconds() as i32;
+        if delta.is_negative() {
+            self.rtc_offset -=
+                RtcDateTimeOffset(unsafe { RangedU32::new_unchecked(delta.unsigned_abs()) });
+        } else {
+            self.rtc_offset +=
+                RtcDateTimeOffset(unsafe { RangedU32::new_unchecked(delta.unsigned_abs()) });
+        }
+    }
+}
+
+/// Performs the given read, retrying up to `retries` times on transient errors.
+fn retry<T, F>(retries: u8, mut read: F) -> Result<T, Error>
+where
+    F: FnMut() -> Result<T, Error>,
+{
+    let mut remaining = retries;
+    loop {
+        match read() {
+            Err(error) if error.is_transient() && remaining > 0 => remaining -= 1,
+            result => return result,
+        }
+    }
+}
+
+/// Returns the amount of time from `now` until the next occurrence of `target` within a day.
+///
+/// This is zero if `target` is equal to `now`.
+fn duration_until_daily(now: Time, target: Time) -> Duration {
+    let duration = target - now;
+    if duration.is_negative() {
+        duration + Duration::DAY
+    } else {
+        duration
+    }
+}
+
+/// Returns the start of the day after `date`.
+///
+/// Returns [`Error::Overflow`] if `date` is [`Date::MAX`], as the following day cannot be
+/// represented.
+fn next_midnight(date: Date) -> Result<PrimitiveDateTime, Error> {
+    date.next_day().map(Date::midnight).ok_or(Error::Overflow)
+}
+
+/// Returns the number of whole minutes elapsed between the start of the Julian day numbering and
+/// `datetime`.
+fn julian_minute(datetime: PrimitiveDateTime) -> i64 {
+    i64::from(datetime.to_julian_day()) * 1440
+        + i64::from(datetime.hour()) * 60
+        + i64::from(datetime.minute())
+}
+
+/// Returns the day of the week of `date` as a number in the range `1..=7`, starting from either
+/// Monday or Sunday.
+fn weekday_number(date: Date, monday_based: bool) -> u8 {
+    if monday_based {
+        date.weekday().number_from_monday()
+    } else {
+        date.weekday().number_from_sunday()
+    }
+}
+
+/// Converts `time` to 12-hour form, returning the hour in the range `1..=12`, the minute, the
+/// second, and whether the time is PM.
+fn time_12h(time: Time) -> (u8, u8, u8, bool) {
+    let hour = match time.hour() % 12 {
+        0 => 12,
+        hour => hour,
+    };
+    (hour, time.minute(), time.second(), time.hour() >= 12)
+}
+
+/// Adds the display shift `shift` to `datetime`.
+///
+/// Returns [`Error::Overflow`] if a positive shift moves `datetime` past the latest representable
+/// date and time, or [`Error::Underflow`] if a negative shift moves it before the earliest.
+fn apply_display_shift(
+    datetime: PrimitiveDateTime,
+    shift: Duration,
+) -> Result<PrimitiveDateTime, Error> {
+    datetime.checked_add(shift).ok_or(if shift.is_negative() {
+        Error::Underflow
+    } else {
+        Error::Overflow
+    })
+}
+
+/// Replaces an [`Error::Overflow`] in `result` with [`Clock::MAX_DATETIME`].
+fn clamp_overflow(result: Result<PrimitiveDateTime, Error>) -> Result<PrimitiveDateTime, Error> {
+    match result {
+        Err(Error::Overflow) => Ok(Clock::MAX_DATETIME),
+        result => result,
+    }
+}
+
+/// Returns the number of seconds from `time` until the next whole minute.
+///
+/// This is `60` if `time` is exactly on a minute boundary.
+fn seconds_to_next_minute(time: Time) -> u8 {
+    60 - time.second()
+}
+
+/// Advances the whole-second `time` by `frames` sixtieths of a second.
+///
+/// `frames` is capped at `59`, so the result never reaches the following second.
+fn interpolate_time(time: Time, frames: u8) -> Time {
+    time + Duration::nanoseconds(i64::from(frames.min(59)) * 1_000_000_000 / 60)
+}
+
+/// Returns the two ASCII digits of `value`, which must be less than `100`.
+fn ascii_digits(value: u8) -> [u8; 2] {
+    [b'0' + value / 10, b'0' + value % 10]
+}
+
+/// Formats `time` as `HH:MM:SS` in ASCII.
+fn format_time_ascii(time: Time) -> [u8; 8] {
+    let [hour_0, hour_1] = ascii_digits(time.hour());
+    let [minute_0, minute_1] = ascii_digits(time.minute());
+    let [second_0, second_1] = ascii_digits(time.second());
+    [
+        hour_0, hour_1, b':', minute_0, minute_1, b':', second_0, second_1,
+    ]
+}
+
+/// Formats `date` as `YYYY-MM-DD` in ASCII.
+///
+/// Returns [`Error::Overflow`] if the year is after 9999, or [`Error::Underflow`] if it is
+/// negative.
+fn format_date_ascii(date: Date) -> Result<[u8; 10], Error> {
+    let year = u16::try_from(date.year()).map_err(|_| Error::Underflow)?;
+    if year > 9999 {
+        return Err(Error::Overflow);
+    }
+    let [year_0, year_1] = ascii_digits((year / 100) as u8);
+    let [year_2, year_3] = ascii_digits((year % 100) as u8);
+    let [month_0, month_1] = ascii_digits(date.month().into());
+    let [day_0, day_1] = ascii_digits(date.day());
+    Ok([
+        year_0, year_1, year_2, year_3, b'-', month_0, month_1, b'-', day_0, day_1,
+    ])
+}
+
+/// Parses the `len` ASCII decimal digits of `bytes` beginning at `start`.
+///
+/// Returns `None` if any of the bytes is not a digit.
+const fn parse_ascii_digits(bytes: &[u8], start: usize, len: usize) -> Option<u16> {
+    let mut value = 0;
+    let mut index = start;
+    while index < start + len {
+        let byte = bytes[index];
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (byte - b'0') as u16;
+        index += 1;
+    }
+    Some(value)
+}
+
+/// Returns the `Month` numbered `number`, starting from `1` for January.
+///
+/// This is the same as `Month::try_from()`, but usable in `const` contexts.
+const fn month_from_number(number: u8) -> Option<Month> {
+    Some(match number {
+        1 => Month::January,
+        2 => Month::February,
+        3 => Month::March,
+        4 => Month::April,
+        5 => Month::May,
+        6 => Month::June,
+        7 => Month::July,
+        8 => Month::August,
+        9 => Month::September,
+        10 => Month::October,
+        11 => Month::November,
+        12 => Month::December,
+        _ => return None,
+    })
+}
+
+/// Performs the given read, calling `fix` and reading once more if the read finds the RTC in
+/// 12-hour mode and `enabled` is `true`.
+fn fix_hour_mode<T, R, F>(enabled: bool, mut read: R, fix: F) -> Result<T, Error>
+where
+    R: FnMut() -> Result<T, Error>,
+    F: FnOnce() -> Result<(), Error>,
+{
+    match read() {
+        Err(Error::AmPmBitPresent) if enabled => {
+            fix()?;
+            read()
+        }
+        result => result,
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Clock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // A software `Clock`'s simulated RTC value cannot be restored, as deserializing reads from
+        // the RTC.
+        if self.is_software() {
+            return Err(S::Error::custom(Error::SoftwareClock));
+        }
+        let mut r#struct = serializer.serialize_struct("Clock", 2)?;
+        r#struct.serialize_field("base_date", &self.base_date)?;
+        r#struct.serialize_field("rtc_offset", &self.rtc_offset)?;
+        r#struct.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Clock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        enum Field {
+            BaseDate,
+            RtcOffset,
+        }
+
+        impl<'de> Deserialize<'de> for Field {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct FieldVisitor;
+
+                impl<'de> Visitor<'de> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                        formatter.write_str("`base_date` or `rtc_offset`")
+                    }
+
+                    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            0 => Ok(Field::BaseDate),
+                            1 => Ok(Field::RtcOffset),
+                            _ => Err(de::Error::invalid_value(Unexpected::Unsigned(value), &self)),
+                        }
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            "base_date" => Ok(Field::BaseDate),
+                            "rtc_offset" => Ok(Field::RtcOffset),
+                            _ => Err(de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+
+                    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            b"base_date" => Ok(Field::BaseDate),
+                            b"rtc_offset" => Ok(Field::RtcOffset),
+                            _ => {
+                                let utf8_value =
+                                    str::from_utf8(value).unwrap_or("\u{fffd}\u{fffd}\u{fffd}");
+                                Err(de::Error::unknown_field(utf8_value, FIELDS))
+                            }
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        struct ClockVisitor;
+
+        impl<'de> Visitor<'de> for ClockVisitor {
+            type Value = Clock;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("struct Clock")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let base_date = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let rtc_offset = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok(Clock::from_parts(base_date, rtc_offset))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut base_date = None;
+                let mut rtc_offset = None;
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::BaseDate => {
+                            if base_date.is_some() {
+                                return Err(de::Error::duplicate_field("base_date"));
+                            }
+                            base_date = Some(map.next_value()?);
+                        }
+                        Field::RtcOffset => {
+                            if rtc_offset.is_some() {
+                                return Err(de::Error::duplicate_field("rtc_offset"));
+                            }
+                            rtc_offset = Some(map.next_value()?);
+                        }
+                    }
+                }
+
+                Ok(Clock::from_parts(
+                    base_date.ok_or_else(|| de::Error::missing_field("base_date"))?,
+                    rtc_offset.ok_or_else(|| de::Error::missing_field("rtc_offset"))?,
+                ))
+            }
+        }
+
+        const FIELDS: &[&str] = &["base_date", "rtc_offset"];
+        let result = deserializer.deserialize_struct("Clock", FIELDS, ClockVisitor);
+        if result.is_ok() {
+            // Enable operations with the RTC via General Purpose I/O (GPIO).
+            enable();
+            set_status(Status::HOUR_24).map_err(|error| {
+                de::Error::custom(format_args!(
+                    "could not set RTC status 24 hour bit: {}",
+                    error
+                ))
+            })?;
+            // If the power bit is active, the clock is unreadable.
+            let status = try_read_status().map_err(|error| {
+                de::Error::custom(format_args!("could not read RTC status: {}", error))
+            })?;
+            if status.contains(&Status::POWER) {
+                return Err(de::Error::custom(
+                    "RTC power bit is set, RTC needs to be reset",
+                ));
+            }
+            // If we are in test mode, the clock is unreadable.
+            if is_test_mode().map_err(|error| {
+                de::Error::custom(format_args!(
+                    "could not detect if RTC is in test mode: {}",
+                    error
+                ))
+            })? {
+                return Err(de::Error::custom("RTC is in test mode and must be reset"));
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+#[no_mangle]
+pub fn main() {
+    let _ = mgba_log::init();
+    test_harness()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "read-only"))]
+    use super::InterruptMode;
+    use super::{
+        apply_display_shift,
+        clamp_overflow,
+        duration_until_daily,
+        fix_hour_mode,
+        format_date_ascii,
+        format_time_ascii,
+        gpio,
+        interpolate_time,
+        julian_minute,
+        next_midnight,
+        seconds_to_next_minute,
+        time_12h,
+        weekday_number,
+        Clock,
+        ClockBuilder,
+        DateTimeComponents,
+        Error,
+        Snapshot,
+        Status,
+    };
+    use crate::{
+        date_time::{
+            RtcDateTimeOffset,
+            RTC_MAX_SECONDS,
+        },
+        ranged::RangedU32,
+    };
+    #[cfg(feature = "serde")]
+    use claims::assert_err;
+    #[cfg(not(feature = "read-only"))]
+    use claims::assert_some_eq;
+    use claims::{
+        assert_err_eq,
+        assert_ok,
+        assert_ok_eq,
+        assert_some,
+    };
+    use core::cmp::Ordering;
+    use gba_test::test;
+    use time::{
+        Date,
+        Duration,
+        Month,
+        PrimitiveDateTime,
+        UtcOffset,
+    };
+    use time_macros::{
+        date,
+        datetime,
+        offset,
+        time,
+    };
+
+    #[test]
+    fn retry_succeeds_with_retries() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        clock.with_retries(1);
+        let mut failed = false;
+
+        assert_ok_eq!(
+            clock.retry(|| if failed {
+                Ok(42)
+            } else {
+                failed = true;
+                Err(Error::InvalidBinaryCodedDecimal(0xff))
+            }),
+            42
+        );
+    }
+
+    #[test]
+    fn retry_fails_without_retries() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        let mut failed = false;
+
+        assert_err_eq!(
+            clock.retry(|| if failed {
+                Ok(42)
+            } else {
+                failed = true;
+                Err(Error::InvalidBinaryCodedDecimal(0xff))
+            }),
+            Error::InvalidBinaryCodedDecimal(0xff)
+        );
+    }
+
+    #[test]
+    fn retry_does_not_retry_non_transient() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        clock.with_retries(1);
+        let mut failed = false;
+
+        assert_err_eq!(
+            clock.retry(|| if failed {
+                Ok(42)
+            } else {
+                failed = true;
+                Err(Error::Overflow)
+            }),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    fn fix_hour_mode_succeeds_after_fix() {
+        let mut hour_24 = false;
+        let mut fixed = false;
+
+        assert_ok_eq!(
+            fix_hour_mode(
+                true,
+                || if hour_24 {
+                    Ok(42)
+                } else {
+                    hour_24 = true;
+                    Err(Error::AmPmBitPresent)
+                },
+                || {
+                    fixed = true;
+                    Ok(())
+                }
+            ),
+            42
+        );
+        assert!(fixed);
+    }
+
+    #[test]
+    fn fix_hour_mode_disabled() {
+        let mut fixed = false;
+
+        assert_err_eq!(
+            fix_hour_mode(
+                false,
+                || -> Result<(), Error> { Err(Error::AmPmBitPresent) },
+                || {
+                    fixed = true;
+                    Ok(())
+                }
+            ),
+            Error::AmPmBitPresent
+        );
+        assert!(!fixed);
+    }
+
+    #[test]
+    fn fix_hour_mode_only_retries_once() {
+        let mut reads = 0;
+
+        assert_err_eq!(
+            fix_hour_mode(
+                true,
+                || -> Result<(), Error> {
+                    reads += 1;
+                    Err(Error::AmPmBitPresent)
+                },
+                || Ok(())
+            ),
+            Error::AmPmBitPresent
+        );
+        assert_eq!(reads, 2);
+    }
+
+    #[test]
+    fn fix_hour_mode_fix_fails() {
+        assert_err_eq!(
+            fix_hour_mode(
+                true,
+                || -> Result<(), Error> { Err(Error::AmPmBitPresent) },
+                || Err(Error::NotEnabled)
+            ),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    fn fix_hour_mode_ignores_other_errors() {
+        let mut fixed = false;
+
+        assert_err_eq!(
+            fix_hour_mode(
+                true,
+                || -> Result<(), Error> { Err(Error::InvalidHour(0x24)) },
+                || {
+                    fixed = true;
+                    Ok(())
+                }
+            ),
+            Error::InvalidHour(0x24)
+        );
+        assert!(!fixed);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn self_test() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok!(clock.self_test());
+        // The previous status must be restored.
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn self_test_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.self_test(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn self_test_disabled() {
+        gpio::disable();
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.self_test(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn raw_command_read_status() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        let mut out = [0xff; 3];
+
+        // Read the status register (command 0x63), which outputs a single byte.
+        assert_ok!(unsafe { clock.raw_command(0x63, 1, &mut out) });
+
+        assert_ok_eq!(
+            gpio::Status::try_from(out[0]),
+            assert_ok!(gpio::try_read_status())
+        );
+        // Bytes past `read_len` must be left unchanged.
+        assert_eq!(out[1..], [0xff, 0xff]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn raw_command_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        let mut out = [0; 1];
+
+        assert_err_eq!(
+            unsafe { clock.raw_command(0x63, 1, &mut out) },
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn new_clock_not_enabled() {
+        assert_err_eq!(Clock::new(datetime!(2012-12-21 5:23)), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn new_strict() {
+        let datetime = datetime!(2012-12-21 5:23);
+        // Ensure the RTC has been initialized, clearing any power failure.
+        assert_ok!(Clock::new(datetime));
+        let clock = assert_ok!(Clock::new_strict(datetime));
+
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn new_strict_not_enabled() {
+        assert_err_eq!(
+            Clock::new_strict(datetime!(2012-12-21 5:23)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn new_assume_initialized() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+        let assumed = assert_ok!(Clock::new_assume_initialized(datetime));
+
+        assert_ok_eq!(assumed.read_datetime(), assert_ok!(clock.read_datetime()));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn new_assume_initialized_not_enabled() {
+        assert_err_eq!(
+            Clock::new_assume_initialized(datetime!(2012-12-21 5:23)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    fn to_bytes() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0x1234_5678>()),
+        );
+
+        assert_eq!(
+            clock.to_bytes(),
+            [0xdc, 0x07, 0x64, 0x01, 0x78, 0x56, 0x34, 0x12]
+        );
+    }
+
+    #[test]
+    fn decode_bytes_round_trip() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<409_382_580>()),
+        );
+
+        let decoded = assert_ok!(Clock::decode_bytes(&clock.to_bytes()));
+
+        assert_eq!(decoded.base_date, clock.base_date);
+        assert_eq!(decoded.rtc_offset, clock.rtc_offset);
+    }
+
+    #[test]
+    fn decode_bytes_round_trip_extremes() {
+        let clock = Clock::from_parts(date!(-9999 - 01 - 01), RtcDateTimeOffset(RangedU32::MAX));
+
+        let decoded = assert_ok!(Clock::decode_bytes(&clock.to_bytes()));
+
+        assert_eq!(decoded.base_date, clock.base_date);
+        assert_eq!(decoded.rtc_offset, clock.rtc_offset);
+    }
+
+    #[test]
+    fn decode_bytes_invalid_ordinal() {
+        // 2011 is not a leap year, so it has no 366th day.
+        assert_err_eq!(
+            Clock::decode_bytes(&[0xdb, 0x07, 0x6e, 0x01, 0x00, 0x00, 0x00, 0x00]),
+            Error::InvalidComponent
+        );
+    }
+
+    #[test]
+    fn decode_bytes_invalid_rtc_offset() {
+        assert_err_eq!(
+            Clock::decode_bytes(&[0xdc, 0x07, 0x64, 0x01, 0xff, 0xff, 0xff, 0xff]),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn from_bytes_round_trip() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        let restored = assert_ok!(Clock::from_bytes(&clock.to_bytes()));
+
+        assert_ok_eq!(restored.read_datetime(), assert_ok!(clock.read_datetime()));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn from_bytes_not_enabled() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(Clock::from_bytes(&clock.to_bytes()), Error::NotEnabled);
+    }
+
+    #[test]
+    fn from_build_time_str() {
+        assert_ok_eq!(
+            Clock::from_build_time_str("2012-12-21 05:23:42"),
+            datetime!(2012-12-21 5:23:42)
+        );
+    }
+
+    #[test]
+    fn from_build_time_str_iso_separator() {
+        assert_ok_eq!(
+            Clock::from_build_time_str("2012-12-21T05:23:42"),
+            datetime!(2012-12-21 5:23:42)
+        );
+    }
+
+    #[test]
+    fn from_build_time_str_const() {
+        const BUILD_TIME: Result<PrimitiveDateTime, Error> =
+            Clock::from_build_time_str("2099-12-31 23:59:59");
+
+        assert_ok_eq!(BUILD_TIME, datetime!(2099-12-31 23:59:59));
+    }
+
+    #[test]
+    fn from_build_time_str_wrong_length() {
+        assert_err_eq!(
+            Clock::from_build_time_str("2012-12-21 05:23"),
+            Error::InvalidComponent
+        );
+    }
+
+    #[test]
+    fn from_build_time_str_wrong_separator() {
+        assert_err_eq!(
+            Clock::from_build_time_str("2012/12/21 05:23:42"),
+            Error::InvalidComponent
+        );
+    }
+
+    #[test]
+    fn from_build_time_str_not_digit() {
+        assert_err_eq!(
+            Clock::from_build_time_str("2012-12-2a 05:23:42"),
+            Error::InvalidComponent
+        );
+    }
+
+    #[test]
+    fn from_build_time_str_invalid_month() {
+        assert_err_eq!(
+            Clock::from_build_time_str("2012-13-21 05:23:42"),
+            Error::InvalidComponent
+        );
+    }
+
+    #[test]
+    fn from_build_time_str_invalid_date() {
+        assert_err_eq!(
+            Clock::from_build_time_str("2013-02-29 05:23:42"),
+            Error::InvalidComponent
+        );
+    }
+
+    #[test]
+    fn from_build_time_str_invalid_time() {
+        assert_err_eq!(
+            Clock::from_build_time_str("2012-12-21 24:00:00"),
+            Error::InvalidComponent
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_datetime(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_after_disabled() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        gpio::disable();
+
+        assert_err_eq!(clock.read_datetime(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_raw_bytes() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        let bytes = assert_ok!(clock.read_raw_bytes());
+
+        // Every byte read from a functioning RTC is valid binary coded decimal.
+        for byte in bytes {
+            assert!(byte & 0x0f < 10);
+            assert!(byte >> 4 < 10);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_raw_bytes_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_raw_bytes(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_mut() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let mut clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(clock.read_datetime_mut(), datetime);
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_mut_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_ok_eq!(clock.read_datetime_mut(), datetime!(2012-12-21 6:23));
+    }
+
+    #[test]
+    fn read_datetime_mut_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.read_datetime_mut(), datetime!(2012-12-20 23:23));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_mut_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_datetime_mut(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_interruptible() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(clock.read_datetime_interruptible(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_interruptible_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_datetime_interruptible(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn elapsed_core() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok!(clock.elapsed_core());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn elapsed_core_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.elapsed_core(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn elapsed_core_at() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+
+        assert_eq!(
+            clock.elapsed_core_at(RtcDateTimeOffset(RangedU32::new_static::<142>())),
+            core::time::Duration::from_secs(42)
+        );
+    }
+
+    #[test]
+    fn elapsed_core_at_zero() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+
+        assert_eq!(
+            clock.elapsed_core_at(RtcDateTimeOffset(RangedU32::new_static::<100>())),
+            core::time::Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn elapsed_core_at_wrapped() {
+        // The stored time was set to 05:23:00 when the RTC read 2000-01-01 00:00:10, so the stored
+        // RTC offset wrapped around to before the RTC's origin.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<10>())
+                - RtcDateTimeOffset(RangedU32::new_static::<19_380>()),
+        );
+
+        assert_eq!(
+            clock.elapsed_core_at(RtcDateTimeOffset(RangedU32::new_static::<20>())),
+            core::time::Duration::from_secs(19_390)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_after() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.is_after(datetime!(2012-12-21 5:22:59)), true);
+        assert_ok_eq!(clock.is_after(datetime!(2012-12-21 5:23)), false);
+        assert_ok_eq!(clock.is_after(datetime!(2012-12-21 5:23:01)), false);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_before() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.is_before(datetime!(2012-12-21 5:22:59)), false);
+        assert_ok_eq!(clock.is_before(datetime!(2012-12-21 5:23)), false);
+        assert_ok_eq!(clock.is_before(datetime!(2012-12-21 5:23:01)), true);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_between_start_inclusive() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(
+            clock.is_between(datetime!(2012-12-21 5:23), datetime!(2012-12-21 6:00)),
+            true
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_between_end_exclusive() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(
+            clock.is_between(datetime!(2012-12-21 5:00), datetime!(2012-12-21 5:23)),
+            false
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_between_empty() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(
+            clock.is_between(datetime!(2012-12-21 5:23), datetime!(2012-12-21 5:23)),
+            false
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn is_between_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.is_between(datetime!(2012-12-21 0:00), datetime!(2012-12-22 0:00)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    fn compare_at_less() {
+        // The current time is 2012-12-21 05:23:00.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.compare_at(rtc_offset, datetime!(2012-12-21 5:23:01)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn compare_at_equal() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.compare_at(rtc_offset, datetime!(2012-12-21 5:23)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn compare_at_greater() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.compare_at(rtc_offset, datetime!(2012-12-21 5:22:59)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn compare_at_display_shift() {
+        // The stored time is 2012-12-21 05:23:00, displayed as 04:23:00.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        assert_ok!(clock.set_display_shift(-Duration::HOUR));
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.compare_at(rtc_offset, datetime!(2012-12-21 4:23)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn compare_to_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(
+            clock.compare_to(datetime!(2012-12-20 23:23)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn compare_to_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.compare_to(datetime!(2012-12-21 0:00)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    fn detect_rollback_at_forward() {
+        // The current time is 2012-12-21 05:23:00.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.detect_rollback_at(rtc_offset, datetime!(2012-12-21 5:22:59)),
+            false
+        );
+    }
+
+    #[test]
+    fn detect_rollback_at_backward() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.detect_rollback_at(rtc_offset, datetime!(2012-12-21 5:23:01)),
+            true
+        );
+    }
+
+    #[test]
+    fn detect_rollback_at_equal() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.detect_rollback_at(rtc_offset, datetime!(2012-12-21 5:23)),
+            false
+        );
+    }
+
+    #[test]
+    fn detect_rollback_at_display_shift() {
+        // The stored time is 2012-12-21 05:23:00, displayed as 04:23:00.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        assert_ok!(clock.set_display_shift(-Duration::HOUR));
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_380>());
+
+        assert_ok_eq!(
+            clock.detect_rollback_at(rtc_offset, datetime!(2012-12-21 5:00)),
+            true
+        );
+    }
+
+    #[test]
+    fn detect_rollback_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.detect_rollback(datetime!(2012-12-21 0:00)), true);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn detect_rollback_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.detect_rollback(datetime!(2012-12-21 0:00)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn alarms_due() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        let alarms = [
+            datetime!(2012-12-21 5:00),
+            datetime!(2012-12-21 6:00),
+            datetime!(2012-12-21 5:23),
+            datetime!(2013-01-01 0:00),
+            datetime!(2000-01-01 0:00),
+        ];
+
+        assert!(assert_ok!(clock.alarms_due(&alarms)).eq([0, 2, 4]));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn alarms_due_none() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        let alarms = [datetime!(2012-12-21 6:00), datetime!(2013-01-01 0:00)];
+
+        assert!(assert_ok!(clock.alarms_due(&alarms)).eq([]));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn alarms_due_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock
+                .alarms_due(&[datetime!(2000-01-01 0:00)])
+                .map(Iterator::count),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_components() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(
+            clock.read_components(),
+            DateTimeComponents {
+                year: 2012,
+                month: 12,
+                day: 21,
+                hour: 5,
+                minute: 23,
+                second: 0,
+                weekday: 5,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_components_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_components(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn guarded_at_power_failure() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.guarded_at(
+                RtcDateTimeOffset(RangedU32::new_static::<3600>()),
+                Status::POWER
+            ),
+            Error::PowerFailure
+        );
+    }
+
+    #[test]
+    fn guarded_at_no_power_failure() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok_eq!(
+            clock.guarded_at(
+                RtcDateTimeOffset(RangedU32::new_static::<3600>()),
+                Status::HOUR_24
+            ),
+            datetime!(2012-12-21 1:00)
+        );
+    }
+
+    #[test]
+    fn guarded_at_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_ok_eq!(
+            clock.guarded_at(
+                RtcDateTimeOffset(RangedU32::new_static::<3600>()),
+                Status::HOUR_24
+            ),
+            datetime!(2012-12-21 2:00)
+        );
+    }
+
+    #[test]
+    fn guarded_at_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(
+            clock.guarded_at(
+                RtcDateTimeOffset(RangedU32::new_static::<3600>()),
+                Status::HOUR_24
+            ),
+            datetime!(2012-12-20 23:23)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_guarded() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(clock.read_datetime_guarded(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_guarded_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_ok_eq!(clock.read_datetime_guarded(), datetime!(2012-12-21 6:23));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_guarded_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_datetime_guarded(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_and_status() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        let (read_datetime, status) = assert_ok!(clock.read_datetime_and_status());
+
+        assert_eq!(read_datetime, datetime);
+        assert_ok_eq!(clock.read_datetime(), read_datetime);
+        assert_ok_eq!(gpio::try_read_status(), status);
+        assert!(!status.power_failure());
+        assert!(status.hour_24());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_and_status_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        let (read_datetime, _) = assert_ok!(clock.read_datetime_and_status());
+
+        assert_eq!(read_datetime, datetime!(2012-12-21 6:23));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn in_test_mode() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.in_test_mode(), false);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn in_test_mode_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.in_test_mode(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn supports_24_hour() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.supports_24_hour(), true);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn supports_24_hour_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.supports_24_hour(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_frequency_mode() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.is_frequency_mode(), false);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn is_frequency_mode_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.is_frequency_mode(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_and_status_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_datetime_and_status(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn set_interrupt_mode() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok!(clock.set_interrupt_mode(InterruptMode::PerMinute));
+        let status = assert_ok!(gpio::try_read_status());
+        assert_some_eq!(status.interrupt_mode(), InterruptMode::PerMinute);
+        assert!(status.hour_24());
+
+        assert_ok!(clock.set_interrupt_mode(InterruptMode::Off));
+        assert_some_eq!(
+            assert_ok!(gpio::try_read_status()).interrupt_mode(),
+            InterruptMode::Off
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn set_interrupt_mode_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.set_interrupt_mode(InterruptMode::Alarm),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_or_reset() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let mut clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(
+            clock.read_datetime_or_reset(datetime!(2000-01-01 0:00)),
+            datetime
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_or_reset_recovers() {
+        let mut clock = assert_ok!(ClockBuilder::new()
+            .retries(2)
+            .build(datetime!(2012-12-21 5:23)));
+
+        // Simulate a failure, causing the first read to fail.
+        gpio::disable();
+
+        let fallback = datetime!(2000-01-01 0:00);
+        assert_ok_eq!(clock.read_datetime_or_reset(fallback), fallback);
+        assert_eq!(clock.retries, 2);
+        assert_ok_eq!(clock.read_datetime(), fallback);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_or_reset_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.read_datetime_or_reset(datetime!(2000-01-01 0:00)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn recover() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok!(clock.recover(datetime!(2000-01-01 0:00)));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2000-01-01 0:00));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn recover_preserves_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_ok!(clock.recover(datetime!(2000-01-01 0:00)));
+
+        assert_eq!(clock.display_shift(), Duration::HOUR);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2000-01-01 1:00));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn snapshot() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        let snapshot = assert_ok!(clock.snapshot());
+
+        assert_eq!(snapshot.datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn snapshot_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        let snapshot = assert_ok!(clock.snapshot());
+
+        assert_eq!(snapshot.datetime(), datetime!(2012-12-21 6:23));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn snapshot_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.snapshot(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn frames_since() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        let snapshot = assert_ok!(clock.snapshot());
+
+        assert_eq!(assert_ok!(clock.frames_since(&snapshot)) % 60, 0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn frames_since_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 0:00),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        };
+
+        assert_err_eq!(clock.frames_since(&snapshot), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_unix_timestamp_origin() {
+        let clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+
+        assert_ok_eq!(clock.read_unix_timestamp(), 946_684_800);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_utc() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        let datetime = assert_ok!(clock.read_utc());
+        assert_eq!(datetime.offset(), UtcOffset::UTC);
+        assert_ok_eq!(
+            clock.read_datetime(),
+            PrimitiveDateTime::new(datetime.date(), datetime.time())
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_utc_ignores_utc_offset() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        clock.set_utc_offset(offset!(+9));
+
+        assert_ok_eq!(clock.read_utc(), datetime!(2012-12-21 5:23 UTC));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_utc_ignores_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_ok_eq!(clock.read_utc(), datetime!(2012-12-21 5:23 UTC));
+    }
+
+    #[test]
+    fn utc_at_ignores_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_ok_eq!(
+            clock.utc_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())),
+            datetime!(2012-12-21 5:23 UTC)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_utc_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_utc(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_unix_timestamp() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.read_unix_timestamp(), 1_356_067_380);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_unix_timestamp_utc_offset() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 9:00)));
+        clock.set_utc_offset(offset!(+9));
+
+        assert_ok_eq!(clock.read_unix_timestamp(), 946_684_800);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_unix_timestamp_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_unix_timestamp(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_datetime() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+        let datetime = datetime!(2012-12-21 5:23);
+
+        assert_ok!(clock.write_datetime(datetime));
+
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn write_datetime_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.write_datetime(datetime!(2012-12-21 5:23)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_datetime_verified() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+
+        assert_ok!(clock.write_datetime_verified(datetime!(2012-12-21 5:23)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn verify_at_matches() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok!(clock.verify_at(
+            datetime!(2012-12-21 5:23),
+            RtcDateTimeOffset(RangedU32::new_static::<19_380>())
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn verify_at_ticked() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok!(clock.verify_at(
+            datetime!(2012-12-21 5:23:00.5),
+            RtcDateTimeOffset(RangedU32::new_static::<19_381>())
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn verify_at_too_late() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.verify_at(
+                datetime!(2012-12-21 5:23),
+                RtcDateTimeOffset(RangedU32::new_static::<19_382>())
+            ),
+            Error::VerifyFailed
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn verify_at_wrong_base_date() {
+        // A base date that does not match what was written causes reads to diverge.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 22),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.verify_at(
+                datetime!(2012-12-21 5:23),
+                RtcDateTimeOffset(RangedU32::new_static::<19_380>())
+            ),
+            Error::VerifyFailed
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn verify_at_earlier() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.verify_at(
+                datetime!(2012-12-21 5:23),
+                RtcDateTimeOffset(RangedU32::new_static::<19_379>())
+            ),
+            Error::VerifyFailed
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn write_datetime_verified_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.write_datetime_verified(datetime!(2012-12-21 5:23)),
+            Error::NotEnabled
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_datetime_after_disabled() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let mut clock = assert_ok!(Clock::new(datetime));
+
+        gpio::disable();
+
+        assert_err_eq!(clock.write_datetime(datetime), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_unix_timestamp_origin() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok!(clock.write_unix_timestamp(946_684_800));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2000-01-01 0:00));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_unix_timestamp() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+
+        assert_ok!(clock.write_unix_timestamp(1_356_067_380));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_unix_timestamp_utc_offset() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        clock.set_utc_offset(offset!(-5));
+
+        assert_ok!(clock.write_unix_timestamp(946_702_800));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2000-01-01 0:00));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_unix_timestamp_before_range() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        // 1999-12-31 23:59:59
+        assert_err_eq!(
+            clock.write_unix_timestamp(946_684_799),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_unix_timestamp_after_range() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        // 2100-01-01 00:00:00
+        assert_err_eq!(
+            clock.write_unix_timestamp(4_102_444_800),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn sync_offset() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let mut clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok!(clock.sync_offset());
+
+        assert_ok_eq!(clock.read_datetime(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn sync_offset_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.sync_offset(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn rebase_at_preserves_datetime() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_234_567>());
+        let datetime = assert_ok!(clock.datetime_at(rtc_offset));
+
+        assert_ok!(clock.rebase_at(rtc_offset));
+
+        assert_ok_eq!(clock.datetime_at(rtc_offset), datetime);
+        assert_eq!(clock.base_date, datetime.date());
+    }
+
+    #[test]
+    fn rebase_at_preserves_datetime_wrapped() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<3_155_759_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<42>());
+        let datetime = assert_ok!(clock.datetime_at(rtc_offset));
+
+        assert_ok!(clock.rebase_at(rtc_offset));
+
+        assert_ok_eq!(clock.datetime_at(rtc_offset), datetime);
+        assert_eq!(clock.base_date, datetime.date());
+    }
+
+    #[test]
+    fn software_reads() {
+        let clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        assert!(clock.is_software());
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+        assert_ok_eq!(clock.read_date(), date!(2012 - 12 - 21));
+        assert_ok_eq!(clock.read_time(), time!(5:23));
+    }
+
+    #[test]
+    fn software_tick_one_hour() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        clock.tick(3600);
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 6:23));
+        assert_ok_eq!(clock.read_time(), time!(6:23));
+    }
+
+    #[test]
+    fn software_tick_across_midnight() {
+        let mut clock = Clock::new_software(datetime!(2012-12-31 23:30));
+
+        clock.tick(3600);
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2013-01-01 0:30));
+        assert_ok_eq!(clock.read_date(), date!(2013 - 01 - 01));
+        assert_ok_eq!(clock.read_time(), time!(0:30));
+    }
+
+    #[test]
+    fn software_tick_many_days() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        clock.tick(10 * 86_400);
+        clock.tick(86_400);
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2013-01-01 5:23));
+    }
+
+    #[test]
+    fn software_display_shift() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-20 23:23));
+        assert_ok_eq!(clock.read_date(), date!(2012 - 12 - 20));
+        assert_ok_eq!(clock.read_time(), time!(23:23));
+    }
+
+    #[test]
+    fn software_overflow() {
+        let mut clock = Clock::new_software(datetime!(9999-12-31 23:30));
+
+        clock.tick(3600);
+
+        assert_err_eq!(clock.read_datetime(), Error::Overflow);
+        assert_ok_eq!(clock.read_datetime_clamped(), Clock::MAX_DATETIME);
+    }
+
+    #[test]
+    fn software_reads_without_rtc() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        assert_ok_eq!(clock.read_datetime_mut(), datetime!(2012-12-21 5:23));
+        assert_ok_eq!(
+            clock.read_datetime_interruptible(),
+            datetime!(2012-12-21 5:23)
+        );
+        assert_ok_eq!(
+            clock.read_datetime_lenient(),
+            (date!(2012 - 12 - 21), Some(time!(5:23)))
+        );
+        assert_ok_eq!(clock.cached().read_datetime(), datetime!(2012-12-21 5:23));
+        assert_ok_eq!(clock.read_hour_minute(), (5, 23));
+        assert_ok_eq!(clock.seconds_of_day(), 19_380);
+        assert_ok_eq!(clock.read_unix_timestamp(), 1_356_067_380);
+        assert_ok_eq!(clock.read_raw_offset(), 19_380);
+        assert_ok_eq!(clock.next_midnight(), datetime!(2012-12-22 0:00));
+    }
+
+    #[test]
+    fn software_tick_advances_raw_offset() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        clock.tick(90);
+
+        assert_ok_eq!(clock.read_raw_offset(), 19_470);
+        assert_ok_eq!(clock.read_delta(), (19_470, false));
+    }
+
+    #[test]
+    fn software_tick_wraps() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        clock.tick(RTC_MAX_SECONDS);
+
+        // The simulated RTC value wraps around just as the RTC's does.
+        assert_ok_eq!(clock.read_raw_offset(), 19_379);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:22:59));
+    }
+
+    #[test]
+    fn software_snapshot() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        let earlier = assert_ok!(clock.snapshot());
+        clock.tick(90);
+        let later = assert_ok!(clock.snapshot());
+
+        assert_eq!(earlier.datetime(), datetime!(2012-12-21 5:23));
+        assert_eq!(later.datetime(), datetime!(2012-12-21 5:24:30));
+        assert_eq!(later.duration_since(&earlier), Duration::seconds(90));
+        assert_ok_eq!(clock.frames_since(&earlier), 5400);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn software_write_datetime_then_read() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+        clock.tick(3600);
+
+        assert_ok!(clock.write_datetime(datetime!(2020-06-15 12:00)));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2020-06-15 12:00));
+        clock.tick(90);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2020-06-15 12:01:30));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn software_write_date_then_read() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+        clock.tick(3600);
+
+        assert_ok!(clock.write_date(date!(2020 - 06 - 15)));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2020-06-15 6:23));
+        clock.tick(3600);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2020-06-15 7:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn software_write_time_then_read() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        assert_ok!(clock.write_time(time!(23:59)));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 23:59));
+        clock.tick(120);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-22 0:01));
+        assert_ok_eq!(clock.read_time(), time!(0:01));
+    }
+
+    #[test]
+    fn software_pause_resume() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        assert_ok!(clock.pause());
+        clock.tick(3600);
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+
+        assert_ok!(clock.resume());
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+        clock.tick(60);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:24));
+    }
+
+    #[test]
+    fn software_sync_offset() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+        clock.tick(86_400);
+
+        assert_ok!(clock.sync_offset());
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-22 5:23));
+        assert_ok_eq!(clock.read_delta(), (19_380, false));
+    }
+
+    #[test]
+    fn software_rtc_only_methods() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+
+        assert_err_eq!(clock.read_raw_bytes(), Error::SoftwareClock);
+        assert_err_eq!(clock.read_datetime_and_status(), Error::SoftwareClock);
+        assert_err_eq!(clock.read_datetime_guarded(), Error::SoftwareClock);
+        assert_err_eq!(clock.is_frequency_mode(), Error::SoftwareClock);
+        assert_err_eq!(clock.supports_24_hour(), Error::SoftwareClock);
+        assert_err_eq!(clock.in_test_mode(), Error::SoftwareClock);
+        assert_err_eq!(
+            clock.recover(datetime!(2020-06-15 12:00)),
+            Error::SoftwareClock
+        );
+        // The stored date and time are not discarded by the failed recovery.
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn software_rtc_only_writes() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+        let mut out = [0; 1];
+
+        assert_err_eq!(clock.self_test(), Error::SoftwareClock);
+        assert_err_eq!(
+            clock.set_interrupt_mode(InterruptMode::Off),
+            Error::SoftwareClock
+        );
+        assert_err_eq!(
+            unsafe { clock.raw_command(0x63, 1, &mut out) },
+            Error::SoftwareClock
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn software_serialize() {
+        let clock = Clock::new_software(datetime!(2012-12-21 5:23));
+        let mut buffer = [0; 16];
+
+        assert_err!(postcard::to_slice(&clock, &mut buffer));
+    }
+
+    #[test]
+    fn tick_not_software() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        clock.tick(3600);
+
+        assert!(!clock.is_software());
+    }
+
+    #[test]
+    fn paused_reads_constant() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert!(clock.is_paused());
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+        assert_ok_eq!(clock.read_date(), date!(2012 - 12 - 21));
+        assert_ok_eq!(clock.read_time(), time!(5:23));
+    }
+
+    #[test]
+    fn paused_reads_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-20 23:23));
+        assert_ok_eq!(clock.read_date(), date!(2012 - 12 - 20));
+        assert_ok_eq!(clock.read_time(), time!(23:23));
+    }
+
+    #[test]
+    fn clamp_overflow_overflowed() {
+        let clock = Clock::from_parts(Date::MAX, RtcDateTimeOffset(RangedU32::MIN));
+
+        assert_ok_eq!(
+            clamp_overflow(
+                clock.shifted_datetime_at(RtcDateTimeOffset(RangedU32::new_static::<86_400>()))
+            ),
+            Clock::MAX_DATETIME
+        );
+    }
+
+    #[test]
+    fn clamp_overflow_not_overflowed() {
+        let clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MIN));
+
+        assert_ok_eq!(
+            clamp_overflow(
+                clock.shifted_datetime_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>()))
+            ),
+            datetime!(2012-12-21 5:23)
+        );
+    }
+
+    #[test]
+    fn clamp_overflow_other_error() {
+        assert_err_eq!(clamp_overflow(Err(Error::NotEnabled)), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_clamped() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(clock.read_datetime_clamped(), datetime);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_clamped_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_datetime_clamped(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn would_overflow_at_near_max() {
+        let clock = Clock::from_parts(Date::MAX, RtcDateTimeOffset(RangedU32::MIN));
+
+        assert!(!clock.would_overflow_at(RtcDateTimeOffset(RangedU32::new_static::<86_399>())));
+        assert!(clock.would_overflow_at(RtcDateTimeOffset(RangedU32::new_static::<86_400>())));
+    }
+
+    #[test]
+    fn would_overflow_at_matches_datetime_at() {
+        let clock = Clock::from_parts(Date::MAX, RtcDateTimeOffset(RangedU32::MAX));
+
+        for seconds in [0, 86_398, 86_399, 86_400] {
+            let rtc_offset = RtcDateTimeOffset(assert_some!(RangedU32::new(seconds)));
+
+            assert_eq!(
+                clock.would_overflow_at(rtc_offset),
+                clock.datetime_at(rtc_offset).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn would_overflow_at_2000() {
+        let clock = Clock::from_parts(date!(2000 - 01 - 01), RtcDateTimeOffset(RangedU32::MIN));
+
+        assert!(!clock.would_overflow_at(RtcDateTimeOffset(RangedU32::MAX)));
+    }
+
+    #[test]
+    fn would_overflow_at_2099() {
+        // Dates past the RTC's own range can still be stored.
+        let clock = Clock::from_parts(date!(2099 - 12 - 31), RtcDateTimeOffset(RangedU32::MIN));
+
+        assert!(!clock.would_overflow_at(RtcDateTimeOffset(RangedU32::MAX)));
+    }
+
+    #[test]
+    fn max_datetime() {
+        assert_eq!(
+            Clock::MAX_DATETIME,
+            PrimitiveDateTime::new(Date::MAX, time!(23:59:59))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn would_overflow() {
+        let clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+
+        assert_ok_eq!(clock.would_overflow(), false);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn would_overflow_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.would_overflow(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn advance_saturating() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<3600>()),
+        );
+
+        clock.advance_saturating(60);
+
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<3600>())),
+            datetime!(2012-12-21 0:01)
+        );
+    }
+
+    #[test]
+    fn advance_saturating_clamps_at_zero() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<60>()),
+        );
+
+        clock.advance_saturating(3600);
+
+        assert_eq!(
+            clock.rtc_offset,
+            RtcDateTimeOffset(RangedU32::new_static::<0>())
+        );
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<60>())),
+            datetime!(2012-12-21 0:01)
+        );
+    }
+
+    #[test]
+    fn advance_saturating_beyond_range() {
+        let mut clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+
+        clock.advance_saturating(u32::MAX);
+
+        assert_eq!(
+            clock.rtc_offset,
+            RtcDateTimeOffset(RangedU32::new_static::<0>())
+        );
+    }
+
+    #[test]
+    fn resume_at_continues_without_jump() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+        // Resume ten minutes later.
+        clock.resume_at(RtcDateTimeOffset(RangedU32::new_static::<19_980>()));
+
+        assert!(!clock.is_paused());
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<19_980>())),
+            datetime!(2012-12-21 5:23)
+        );
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<19_981>())),
+            datetime!(2012-12-21 5:23:01)
+        );
+    }
+
+    #[test]
+    fn resume_at_not_paused() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        clock.resume_at(RtcDateTimeOffset(RangedU32::new_static::<19_980>()));
+
+        assert_eq!(clock.base_date, date!(2012 - 12 - 21));
+        assert_eq!(
+            clock.rtc_offset,
+            RtcDateTimeOffset(RangedU32::new_static::<0>())
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn pause_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.pause(), Error::NotEnabled);
+        assert!(!clock.is_paused());
+    }
+
+    /// Generates RTC values sampled across the RTC's entire range.
+    ///
+    /// This yields every 96,709th second (a stride not aligned to any day, month, or year), along
+    /// with the first second of every month and the second immediately before it. Sampling keeps
+    /// the runtime bounded when running on hardware.
+    fn sample_rtc_values() -> impl Iterator<Item = u32> {
+        let epoch = datetime!(2000-01-01 0:00);
+        let strided = (0..=RTC_MAX_SECONDS).step_by(96_709);
+        let boundaries = (2000..=2099).flat_map(move |year| {
+            (1..=12).flat_map(move |month| {
+                let month = assert_ok!(Month::try_from(month));
+                let first = assert_ok!(Date::from_calendar_date(year, month, 1)).midnight();
+                let seconds = (first - epoch).whole_seconds() as u32;
+                [seconds.saturating_sub(1), seconds]
+            })
+        });
+        strided.chain(boundaries).chain([RTC_MAX_SECONDS])
+    }
+
+    #[test]
+    fn datetime_at_min_offset() {
+        let clock = Clock::from_parts(date!(2000 - 01 - 01), RtcDateTimeOffset(RangedU32::MIN));
+
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::MIN)),
+            datetime!(2000-01-01 0:00)
+        );
+    }
+
+    #[test]
+    fn datetime_at_max_offset() {
+        let clock = Clock::from_parts(date!(2000 - 01 - 01), RtcDateTimeOffset(RangedU32::MIN));
+
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::MAX)),
+            datetime!(2099-12-31 23:59:59)
+        );
+    }
+
+    #[test]
+    fn datetime_at_equal_max_offset() {
+        let clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::MAX)),
+            datetime!(2012-12-21 0:00)
+        );
+    }
+
+    #[test]
+    fn datetime_at_just_wrapped() {
+        let clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+
+        assert_ok_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::MIN)),
+            datetime!(2012-12-21 0:00:01)
+        );
+    }
+
+    #[test]
+    fn datetime_at_overflow() {
+        let clock = Clock::from_parts(Date::MAX, RtcDateTimeOffset(RangedU32::MIN));
+
+        assert_err_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<86_400>())),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    fn datetime_at_overflow_wrapped() {
+        let clock = Clock::from_parts(Date::MAX, RtcDateTimeOffset(RangedU32::MAX));
+
+        assert_err_eq!(
+            clock.datetime_at(RtcDateTimeOffset(RangedU32::new_static::<86_399>())),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    fn delta_at() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000>()),
+        );
+
+        let (elapsed, wrapped) =
+            clock.delta_at(RtcDateTimeOffset(RangedU32::new_static::<1_060>()));
+        assert_eq!(elapsed.get(), 60);
+        assert!(!wrapped);
+    }
+
+    #[test]
+    fn delta_at_equal() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000>()),
+        );
+
+        let (elapsed, wrapped) =
+            clock.delta_at(RtcDateTimeOffset(RangedU32::new_static::<1_000>()));
+        assert_eq!(elapsed.get(), 0);
+        assert!(!wrapped);
+    }
+
+    #[test]
+    fn delta_at_wrapped() {
+        let clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+
+        let (elapsed, wrapped) = clock.delta_at(RtcDateTimeOffset(RangedU32::new_static::<59>()));
+        assert_eq!(elapsed.get(), 60);
+        assert!(wrapped);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_delta_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_delta(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn datetime_at_matches_time_arithmetic() {
+        let epoch = datetime!(2000-01-01 0:00);
+        let clock = Clock::from_parts(epoch.date(), RtcDateTimeOffset(RangedU32::MIN));
+
+        for seconds in sample_rtc_values() {
+            let rtc_offset = RtcDateTimeOffset(assert_some!(RangedU32::new(seconds)));
+            let expected = epoch + Duration::seconds(seconds.into());
+
+            assert_ok_eq!(clock.datetime_at(rtc_offset), expected);
+            assert_ok_eq!(RtcDateTimeOffset::try_from(expected), rtc_offset);
+        }
+    }
+
+    #[test]
+    fn datetime_at_wrapped_matches_time_arithmetic() {
+        let base_date = date!(2012 - 12 - 21);
+        let stored = RTC_MAX_SECONDS - 86_399;
+        let clock = Clock::from_parts(
+            base_date,
+            RtcDateTimeOffset(assert_some!(RangedU32::new(stored))),
+        );
+
+        for elapsed in sample_rtc_values() {
+            // The RTC's value after `elapsed` seconds, wrapping past the end of its range.
+            let seconds = ((u64::from(stored) + u64::from(elapsed))
+                % (u64::from(RTC_MAX_SECONDS) + 1)) as u32;
+            let rtc_offset = RtcDateTimeOffset(assert_some!(RangedU32::new(seconds)));
+
+            assert_ok_eq!(
+                clock.datetime_at(rtc_offset),
+                base_date.midnight() + Duration::seconds(elapsed.into())
+            );
+        }
+    }
+
+    #[test]
+    fn offset_for_inverts_datetime_at() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+
+        for seconds in sample_rtc_values() {
+            let rtc_offset = RtcDateTimeOffset(assert_some!(RangedU32::new(seconds)));
+            let datetime = assert_ok!(clock.datetime_at(rtc_offset));
+
+            assert_ok_eq!(clock.offset_for(datetime), seconds);
+        }
+    }
+
+    #[test]
+    fn offset_for_wrapped() {
+        let clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+
+        assert_ok_eq!(
+            clock.offset_for(datetime!(2012-12-21 0:00)),
+            RTC_MAX_SECONDS
+        );
+        assert_ok_eq!(clock.offset_for(datetime!(2012-12-21 0:00:01)), 0);
+        assert_ok_eq!(clock.offset_for(datetime!(2012-12-22 0:00)), 86_399);
+    }
+
+    #[test]
+    fn offset_for_sub_seconds_discarded() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+
+        assert_ok_eq!(
+            clock.offset_for(datetime!(2012-12-21 5:23:00.999_999_999)),
+            1_019_380
+        );
+    }
+
+    #[test]
+    fn offset_for_before_base_date() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+
+        assert_err_eq!(
+            clock.offset_for(datetime!(2012-12-20 23:59:59.5)),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    fn offset_for_beyond_range() {
+        let clock = Clock::from_parts(
+            date!(2000 - 01 - 01),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok_eq!(
+            clock.offset_for(datetime!(2099-12-31 23:59:59)),
+            RTC_MAX_SECONDS
+        );
+        assert_err_eq!(
+            clock.offset_for(datetime!(2100-01-01 0:00)),
+            Error::DateOutOfRange
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn offset_for_current_datetime() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        let datetime = assert_ok!(clock.read_datetime());
+
+        assert_ok_eq!(
+            clock.offset_for(datetime),
+            assert_ok!(clock.read_raw_offset())
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_raw_offset_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_raw_offset(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn timed_at() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+
+        assert_ok_eq!(
+            clock.timed_at(RtcDateTimeOffset(RangedU32::new_static::<1_019_380>())),
+            (datetime!(2012-12-21 5:23), 1_019_380)
+        );
+    }
+
+    #[test]
+    fn timed_at_age() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+
+        let (datetime, recorded) =
+            assert_ok!(clock.timed_at(RtcDateTimeOffset(RangedU32::new_static::<1_019_380>())));
+        // The RTC advances by 42 seconds before the reading is consumed.
+        let (now, offset_now) =
+            assert_ok!(clock.timed_at(RtcDateTimeOffset(RangedU32::new_static::<1_019_422>())));
+
+        assert_eq!(offset_now - recorded, 42);
+        assert_eq!(now - datetime, Duration::seconds(42));
+    }
+
+    #[test]
+    fn timed_at_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_ok_eq!(
+            clock.timed_at(RtcDateTimeOffset(RangedU32::new_static::<1_019_380>())),
+            (datetime!(2012-12-21 6:23), 1_019_380)
+        );
+    }
+
+    #[test]
+    fn timed_at_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(
+            clock.timed_at(RtcDateTimeOffset(RangedU32::new_static::<1_019_380>())),
+            (datetime!(2012-12-20 23:23), 1_019_380)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_timed() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        let (datetime, recorded) = assert_ok!(clock.read_timed());
+
+        assert_ok_eq!(clock.offset_for(datetime), recorded);
+        assert!(assert_ok!(clock.offset_now()) >= recorded);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_timed_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_timed(), Error::NotEnabled);
+        assert_err_eq!(clock.offset_now(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_date() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(clock.read_date(), datetime.date());
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_date_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_date(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn set_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+        assert_eq!(clock.display_shift(), Duration::HOUR);
+    }
+
+    #[test]
+    fn set_display_shift_full_day() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ok!(clock.set_display_shift(-Duration::DAY));
+        assert_eq!(clock.display_shift(), -Duration::DAY);
+    }
+
+    #[test]
+    fn set_display_shift_out_of_range() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(
+            clock.set_display_shift(Duration::DAY + Duration::SECOND),
+            Error::InvalidComponent
+        );
+        assert_eq!(clock.display_shift(), Duration::ZERO);
+    }
+
+    #[test]
+    fn shifted_datetime_at_forward() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        // The stored time is 2012-12-21 05:23:00.
+        assert_ok_eq!(
+            clock.shifted_datetime_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())),
+            datetime!(2012-12-21 6:23)
+        );
+    }
+
+    #[test]
+    fn shifted_datetime_at_backward() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(-Duration::HOUR));
+
+        // The stored time is 2012-12-21 05:23:00.
+        assert_ok_eq!(
+            clock.shifted_datetime_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())),
+            datetime!(2012-12-21 4:23)
+        );
+    }
+
+    #[test]
+    fn shifted_datetime_at_overflow() {
+        let mut clock = Clock::from_parts(Date::MAX, RtcDateTimeOffset(RangedU32::MIN));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        // The stored time is 23:30:00 on the last representable date.
+        assert_err_eq!(
+            clock.shifted_datetime_at(RtcDateTimeOffset(RangedU32::new_static::<84_600>())),
+            Error::Overflow
+        );
+    }
+
+    #[test]
+    fn shifted_datetime_at_underflow() {
+        let mut clock = Clock::from_parts(Date::MIN, RtcDateTimeOffset(RangedU32::MIN));
+        assert_ok!(clock.set_display_shift(-Duration::HOUR));
+
+        // The stored time is 00:30:00 on the first representable date.
+        assert_err_eq!(
+            clock.shifted_datetime_at(RtcDateTimeOffset(RangedU32::new_static::<1_800>())),
+            Error::Underflow
+        );
+    }
+
+    #[test]
+    fn apply_display_shift_in_range() {
+        assert_ok_eq!(
+            apply_display_shift(datetime!(2012-12-21 5:23), -Duration::HOUR),
+            datetime!(2012-12-21 4:23)
+        );
+    }
+
+    #[test]
+    fn shifted_date_at_forward_across_midnight() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        // The stored time is 2012-12-21 23:30:00.
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<84_600>());
+        assert_ok_eq!(
+            clock.shifted_datetime_at(rtc_offset),
+            datetime!(2012-12-22 0:30)
+        );
+        assert_ok_eq!(clock.shifted_date_at(rtc_offset), date!(2012 - 12 - 22));
+    }
+
+    #[test]
+    fn shifted_date_at_backward_across_midnight() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(-Duration::HOUR));
+
+        // The stored time is 2012-12-21 00:30:00.
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_800>());
+        assert_ok_eq!(
+            clock.shifted_datetime_at(rtc_offset),
+            datetime!(2012-12-20 23:30)
+        );
+        assert_ok_eq!(clock.shifted_date_at(rtc_offset), date!(2012 - 12 - 20));
+    }
+
+    #[test]
+    fn date_at() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        );
+
+        assert_ok_eq!(
+            clock.date_at(RtcDateTimeOffset(RangedU32::new_static::<86_500>())),
+            date!(2012 - 12 - 22)
+        );
+    }
+
+    #[test]
+    fn clock_is_sync() {
+        fn assert_sync<T: Sync>() {}
+
+        // `Clock` holds no interior mutability, so it can be shared between contexts.
+        assert_sync::<Clock>();
+    }
+
+    #[test]
+    fn date_at_century_wrap() {
+        // The RTC reads 2099-12-31 00:00:00 when the stored date is set.
+        let clock = Clock::from_parts(
+            date!(2098 - 12 - 31),
+            RtcDateTimeOffset(assert_some!(RangedU32::new(RTC_MAX_SECONDS - 86_399))),
+        );
+
+        // The RTC has since wrapped around to 2000-01-01 01:00:00.
+        assert_ok_eq!(
+            clock.date_at(RtcDateTimeOffset(RangedU32::new_static::<3_600>())),
+            date!(2099 - 01 - 01)
+        );
+    }
+
+    #[test]
+    fn date_at_century_wrap_matches_rtc() {
+        // The stored date matches the RTC's own date, so it continues into 2100 rather than
+        // following the RTC back to 2000.
+        let clock = Clock::from_parts(
+            date!(2099 - 12 - 31),
+            RtcDateTimeOffset(assert_some!(RangedU32::new(RTC_MAX_SECONDS - 86_399))),
+        );
+
+        assert_ok_eq!(
+            clock.date_at(RtcDateTimeOffset(RangedU32::new_static::<3_600>())),
+            date!(2100 - 01 - 01)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_lenient() {
+        let datetime = datetime!(2012-12-21 5:23);
+        let clock = assert_ok!(Clock::new(datetime));
+
+        assert_ok_eq!(
+            clock.read_datetime_lenient(),
+            (datetime.date(), Some(datetime.time()))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_datetime_lenient_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+
+        assert_ok_eq!(
+            clock.read_datetime_lenient(),
+            (date!(2012 - 12 - 20), Some(time!(23:23)))
+        );
+    }
+
+    #[test]
+    fn read_datetime_lenient_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(
+            clock.read_datetime_lenient(),
+            (date!(2012 - 12 - 20), Some(time!(23:23)))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_datetime_lenient_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_datetime_lenient(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_leap_day() {
+        let clock = assert_ok!(Clock::new(datetime!(2004-02-29 12:00)));
+
+        assert_ok_eq!(clock.is_leap_day(), true);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn is_leap_day_false() {
+        let clock = assert_ok!(Clock::new(datetime!(2001-02-28 12:00)));
+
+        assert_ok_eq!(clock.is_leap_day(), false);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn is_leap_day_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.is_leap_day(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_iso_week() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-06-15 12:00)));
+
+        assert_ok_eq!(clock.read_iso_week(), (2012, 24));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_iso_week_previous_year() {
+        let clock = assert_ok!(Clock::new(datetime!(2000-01-01 12:00)));
+
+        assert_ok_eq!(clock.read_iso_week(), (1999, 52));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_iso_week_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_iso_week(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_weekday_number() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.read_weekday_number(true), 5);
+        assert_ok_eq!(clock.read_weekday_number(false), 6);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_weekday_number_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_weekday_number(true), Error::NotEnabled);
+    }
+
+    #[test]
+    fn weekday_number_monday_monday_based() {
+        assert_eq!(weekday_number(date!(2012 - 12 - 17), true), 1);
+    }
+
+    #[test]
+    fn weekday_number_monday_sunday_based() {
+        assert_eq!(weekday_number(date!(2012 - 12 - 17), false), 2);
+    }
+
+    #[test]
+    fn weekday_number_sunday_monday_based() {
+        assert_eq!(weekday_number(date!(2012 - 12 - 23), true), 7);
+    }
+
+    #[test]
+    fn weekday_number_sunday_sunday_based() {
+        assert_eq!(weekday_number(date!(2012 - 12 - 23), false), 1);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_ordinal_first_day() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-01-01 12:00)));
+
+        assert_ok_eq!(clock.read_ordinal(), 1);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_ordinal_last_day() {
+        let clock = assert_ok!(Clock::new(datetime!(2013-12-31 12:00)));
+
+        assert_ok_eq!(clock.read_ordinal(), 365);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_ordinal_last_day_leap_year() {
+        let clock = assert_ok!(Clock::new(datetime!(2000-12-31 12:00)));
+
+        assert_ok_eq!(clock.read_ordinal(), 366);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_ordinal_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_ordinal(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_date_after_disabled() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        gpio::disable();
+
+        assert_err_eq!(clock.read_date(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_date() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+
+        assert_ok!(clock.write_date(date!(2012 - 12 - 21)));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 0:00));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn write_date_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.write_date(date!(2012 - 12 - 21)), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_date_after_disabled() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+
+        gpio::disable();
+
+        assert_err_eq!(clock.write_date(date!(2012 - 12 - 21)), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_year() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok!(clock.write_year(2024));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2024-12-21 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_year_leap_day() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2000-02-29 5:23)));
+
+        assert_ok!(clock.write_year(2001));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2001-02-28 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_year_out_of_range() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_err_eq!(clock.write_year(2100), Error::DateOutOfRange);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn write_year_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.write_year(2024), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_month() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok!(clock.write_month(3));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-03-21 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_month_clamps_day() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2013-01-31 5:23)));
+
+        assert_ok!(clock.write_month(2));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2013-02-28 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_month_invalid() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_err_eq!(clock.write_month(13), Error::InvalidMonth(13));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn write_month_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.write_month(3), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_day() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok!(clock.write_day(31));
+
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-31 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_day_nonexistent() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-02-21 5:23)));
+
+        assert_err_eq!(clock.write_day(30), Error::InvalidDay(30));
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-02-21 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn write_day_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.write_day(21), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn duration_until_daily_now() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.duration_until_daily(time!(5:23)), Duration::ZERO);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn duration_until_daily_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.duration_until_daily(time!(5:23)), Error::NotEnabled);
+    }
+
+    #[test]
+    fn duration_until_daily_later_today() {
+        assert_eq!(
+            duration_until_daily(time!(5:23), time!(7:00)),
+            Duration::minutes(97)
+        );
+    }
+
+    #[test]
+    fn duration_until_daily_earlier_wraps() {
+        assert_eq!(
+            duration_until_daily(time!(5:23), time!(5:00)),
+            Duration::hours(23) + Duration::minutes(37)
+        );
+    }
+
+    #[test]
+    fn duration_until_daily_equal() {
+        assert_eq!(
+            duration_until_daily(time!(5:23), time!(5:23)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn duration_until_daily_midnight() {
+        assert_eq!(
+            duration_until_daily(time!(23:59:59), time!(0:00)),
+            Duration::SECOND
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn next_midnight_tomorrow() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.next_midnight(), datetime!(2012-12-22 0:00));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn next_midnight_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.next_midnight(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn next_midnight_mid_month() {
+        assert_ok_eq!(
+            next_midnight(date!(2012 - 12 - 21)),
+            datetime!(2012-12-22 0:00)
+        );
+    }
+
+    #[test]
+    fn next_midnight_end_of_month() {
+        assert_ok_eq!(
+            next_midnight(date!(2012 - 02 - 29)),
+            datetime!(2012-03-01 0:00)
+        );
+    }
+
+    #[test]
+    fn next_midnight_end_of_year() {
+        assert_ok_eq!(
+            next_midnight(date!(2012 - 12 - 31)),
+            datetime!(2013-01-01 0:00)
+        );
+    }
+
+    #[test]
+    fn next_midnight_end_of_2099() {
+        assert_ok_eq!(
+            next_midnight(date!(2099 - 12 - 31)),
+            datetime!(2100-01-01 0:00)
+        );
+    }
+
+    #[test]
+    fn next_midnight_after_2099() {
+        assert_ok_eq!(
+            next_midnight(date!(2100 - 06 - 15)),
+            datetime!(2100-06-16 0:00)
+        );
+    }
+
+    #[test]
+    fn next_midnight_overflow() {
+        assert_err_eq!(next_midnight(Date::MAX), Error::Overflow);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn seconds_to_next_minute_on_minute() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.seconds_to_next_minute(), 60);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn seconds_to_next_minute_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.seconds_to_next_minute(), Error::NotEnabled);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_time_12h() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 17:23)));
+
+        assert_ok_eq!(clock.read_time_12h(), (5, 23, 0, true));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn read_time_12h_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.read_time_12h(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn time_12h_midnight() {
+        assert_eq!(time_12h(time!(0:00)), (12, 0, 0, false));
+    }
+
+    #[test]
+    fn time_12h_morning() {
+        assert_eq!(time_12h(time!(5:23:42)), (5, 23, 42, false));
+    }
+
+    #[test]
+    fn time_12h_noon() {
+        assert_eq!(time_12h(time!(12:00)), (12, 0, 0, true));
+    }
+
+    #[test]
+    fn time_12h_afternoon() {
+        assert_eq!(time_12h(time!(13:00)), (1, 0, 0, true));
+    }
+
+    #[test]
+    fn time_12h_end_of_day() {
+        assert_eq!(time_12h(time!(23:59)), (11, 59, 0, true));
+    }
+
+    #[test]
+    fn seconds_to_next_minute_zero() {
+        assert_eq!(seconds_to_next_minute(time!(5:23:00)), 60);
+    }
+
+    #[test]
+    fn seconds_to_next_minute_one() {
+        assert_eq!(seconds_to_next_minute(time!(5:23:01)), 59);
+    }
+
+    #[test]
+    fn seconds_to_next_minute_fifty_nine() {
+        assert_eq!(seconds_to_next_minute(time!(5:23:59)), 1);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn interpolated_time() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.interpolated_time(0), time!(5:23));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn interpolated_time_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.interpolated_time(0), Error::NotEnabled);
+    }
+
+    #[test]
+    fn format_time_ascii_midnight() {
+        assert_eq!(format_time_ascii(time!(0:00)), *b"00:00:00");
+    }
+
+    #[test]
+    fn format_time_ascii_single_digit_hour() {
+        assert_eq!(format_time_ascii(time!(5:23:42)), *b"05:23:42");
+    }
+
+    #[test]
+    fn format_time_ascii_end_of_day() {
+        assert_eq!(format_time_ascii(time!(23:59:59)), *b"23:59:59");
+    }
+
+    #[test]
+    fn format_date_ascii_full() {
+        assert_ok_eq!(format_date_ascii(date!(2012 - 12 - 21)), *b"2012-12-21");
+    }
+
+    #[test]
+    fn format_date_ascii_single_digit_month_and_day() {
+        assert_ok_eq!(format_date_ascii(date!(2001 - 03 - 09)), *b"2001-03-09");
+    }
+
+    #[test]
+    fn format_date_ascii_small_year() {
+        assert_ok_eq!(format_date_ascii(date!(0042 - 01 - 01)), *b"0042-01-01");
+    }
+
+    #[test]
+    fn format_date_ascii_negative_year() {
+        assert_err_eq!(format_date_ascii(date!(-0001 - 01 - 01)), Error::Underflow);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn write_date_ascii() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        let mut buf = [0; 10];
+
+        assert_ok!(clock.write_date_ascii(&mut buf));
+
+        assert_eq!(buf, *b"2012-12-21");
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn write_time_ascii_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        let mut buf = [0; 8];
+
+        assert_err_eq!(clock.write_time_ascii(&mut buf), Error::NotEnabled);
+        assert_eq!(buf, [0; 8]);
+    }
+
+    #[test]
+    fn interpolate_time_frame_0() {
+        assert_eq!(interpolate_time(time!(5:23:42), 0), time!(5:23:42));
+    }
+
+    #[test]
+    fn interpolate_time_frame_30() {
+        assert_eq!(interpolate_time(time!(5:23:42), 30), time!(5:23:42.5));
+    }
+
+    #[test]
+    fn interpolate_time_frame_59() {
+        let time = interpolate_time(time!(5:23:42), 59);
+
+        assert_eq!(time, time!(5:23:42.983_333_333));
+        assert!(time < time!(5:23:43));
+    }
+
+    #[test]
+    fn interpolate_time_frame_capped() {
+        assert_eq!(
+            interpolate_time(time!(23:59:59), u8::MAX),
+            time!(23:59:59.983_333_333)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn seconds_of_day() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+        assert_ok_eq!(clock.seconds_of_day(), 19_380);
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(no_rtc),
+        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+    )]
+    fn seconds_of_day_not_enabled() {
+        // Manually enable RTC.
+        gpio::enable();
+        // Manually construct a `Clock` object, despite RTC being disabled.
+        //
+        // This is to simulate an RTC failing after `Clock` construction.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_err_eq!(clock.seconds_of_day(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn seconds_of_day_at_midnight() {
+        // The stored time is midnight when the RTC's time is 03:46:40.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        assert_eq!(
+            clock.seconds_of_day_at(RtcDateTimeOffset(RangedU32::new_static::<100_000>()).into()),
+            0
+        );
+    }
+
+    #[test]
+    fn seconds_of_day_at_noon() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        assert_eq!(
+            clock.seconds_of_day_at(RtcDateTimeOffset(RangedU32::new_static::<143_200>()).into()),
+            43_200
+        );
+    }
+
+    #[test]
+    fn seconds_of_day_at_last_second() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        assert_eq!(
+            clock.seconds_of_day_at(RtcDateTimeOffset(RangedU32::new_static::<186_399>()).into()),
+            86_399
+        );
+    }
+
+    #[test]
+    fn seconds_of_day_at_last_second_wrapped() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        assert_eq!(
+            clock.seconds_of_day_at(RtcDateTimeOffset(RangedU32::new_static::<13_599>()).into()),
+            86_399
+        );
+    }
+
+    #[test]
+    fn seconds_of_day_at_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
+
+        assert_eq!(
+            clock.seconds_of_day_at(RtcDateTimeOffset(RangedU32::new_static::<143_200>()).into()),
+            46_800
+        );
+    }
+
+    #[test]
+    fn seconds_of_day_at_display_shift_wrapped() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+        assert_ok!(clock.set_display_shift(-Duration::HOUR));
+
+        assert_eq!(
+            clock.seconds_of_day_at(RtcDateTimeOffset(RangedU32::new_static::<100_000>()).into()),
+            82_800
+        );
+    }
 
     #[test]
-    #[cfg_attr(
-        not(no_rtc),
-        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
-    )]
-    fn new_clock_not_enabled() {
-        assert_err_eq!(Clock::new(datetime!(2012-12-21 5:23)), Error::NotEnabled);
+    fn seconds_of_day_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.seconds_of_day(), 84_180);
     }
 
     #[test]
@@ -492,11 +6369,10 @@ mod tests {
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn read_datetime() {
-        let datetime = datetime!(2012-12-21 5:23);
-        let clock = assert_ok!(Clock::new(datetime));
+    fn minute_fingerprint() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
 
-        assert_ok_eq!(clock.read_datetime(), datetime);
+        assert_ok_eq!(clock.minute_fingerprint(), 3_537_047_843);
     }
 
     #[test]
@@ -504,31 +6380,170 @@ mod tests {
         not(no_rtc),
         ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
     )]
-    fn read_datetime_not_enabled() {
+    fn minute_fingerprint_not_enabled() {
         // Manually enable RTC.
         gpio::enable();
         // Manually construct a `Clock` object, despite RTC being disabled.
         //
         // This is to simulate an RTC failing after `Clock` construction.
-        let clock = Clock {
-            base_date: date!(2012 - 12 - 21),
-            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
-        };
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
 
-        assert_err_eq!(clock.read_datetime(), Error::NotEnabled);
+        assert_err_eq!(clock.minute_fingerprint(), Error::NotEnabled);
     }
 
     #[test]
-    #[cfg_attr(
-        not(rtc),
-        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
-    )]
-    fn read_datetime_after_disabled() {
-        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+    fn minute_fingerprint_at_same_minute() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
 
-        gpio::disable();
+        assert_eq!(
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>()))
+            ),
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_439>()))
+            )
+        );
+    }
 
-        assert_err_eq!(clock.read_datetime(), Error::NotEnabled);
+    #[test]
+    fn minute_fingerprint_at_next_minute() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+
+        assert_ne!(
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_439>()))
+            ),
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_440>()))
+            )
+        );
+    }
+
+    #[test]
+    fn minute_fingerprint_at_unaligned_offset() {
+        // The stored minute changes when the RTC's seconds read 30, rather than 0.
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<30>()),
+        );
+
+        assert_eq!(
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_410>()))
+            ),
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_469>()))
+            )
+        );
+        assert_ne!(
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_409>()))
+            ),
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_410>()))
+            )
+        );
+    }
+
+    #[test]
+    fn minute_fingerprint_at_wrapped() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        // The RTC has wrapped around past its maximum value since the stored offset.
+        assert_ok_eq!(
+            clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<13_599>())),
+            3_589_642_079
+        );
+    }
+
+    #[test]
+    fn minute_fingerprint_at_matches_read_datetime() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<19_380>());
+
+        assert_ok_eq!(
+            clock.minute_fingerprint_at(rtc_offset),
+            julian_minute(assert_ok!(clock.shifted_datetime_at(rtc_offset)))
+        );
+    }
+
+    #[test]
+    fn minute_fingerprint_at_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+
+        assert_ok_eq!(
+            clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())),
+            3_537_047_483
+        );
+    }
+
+    #[test]
+    fn minute_fingerprint_at_display_shift_not_whole_minutes() {
+        // The displayed minute changes when the RTC's seconds read 30, rather than 0.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::seconds(30)));
+
+        assert_eq!(
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_350>()))
+            ),
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_409>()))
+            )
+        );
+        assert_ne!(
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_409>()))
+            ),
+            assert_ok!(
+                clock.minute_fingerprint_at(RtcDateTimeOffset(RangedU32::new_static::<19_410>()))
+            )
+        );
+    }
+
+    #[test]
+    fn minute_fingerprint_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.minute_fingerprint(), 3_537_047_483);
+    }
+
+    #[test]
+    fn minute_fingerprint_software() {
+        let mut clock = Clock::new_software(datetime!(2012-12-21 5:23));
+        let fingerprint = assert_ok!(clock.minute_fingerprint());
+
+        clock.tick(59);
+        assert_ok_eq!(clock.minute_fingerprint(), fingerprint);
+        clock.tick(1);
+        assert_ok_eq!(clock.minute_fingerprint(), fingerprint + 1);
     }
 
     #[test]
@@ -536,13 +6551,10 @@ mod tests {
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn write_datetime() {
-        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
-        let datetime = datetime!(2012-12-21 5:23);
-
-        assert_ok!(clock.write_datetime(datetime));
+    fn day_progress() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
 
-        assert_ok_eq!(clock.read_datetime(), datetime);
+        assert_ok_eq!(clock.day_progress(), (19_380, 86_400));
     }
 
     #[test]
@@ -550,21 +6562,83 @@ mod tests {
         not(no_rtc),
         ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
     )]
-    fn write_datetime_not_enabled() {
+    fn day_progress_not_enabled() {
         // Manually enable RTC.
         gpio::enable();
         // Manually construct a `Clock` object, despite RTC being disabled.
         //
         // This is to simulate an RTC failing after `Clock` construction.
-        let mut clock = Clock {
-            base_date: date!(2012 - 12 - 21),
-            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
-        };
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
 
-        assert_err_eq!(
-            clock.write_datetime(datetime!(2012-12-21 5:23)),
-            Error::NotEnabled
+        assert_err_eq!(clock.day_progress(), Error::NotEnabled);
+    }
+
+    #[test]
+    fn day_progress_at_midnight() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        assert_eq!(
+            clock.day_progress_at(RtcDateTimeOffset(RangedU32::new_static::<100_000>()).into()),
+            (0, 86_400)
+        );
+    }
+
+    #[test]
+    fn day_progress_at_noon() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        assert_eq!(
+            clock.day_progress_at(RtcDateTimeOffset(RangedU32::new_static::<143_200>()).into()),
+            (43_200, 86_400)
+        );
+    }
+
+    #[test]
+    fn day_progress_at_end_of_day() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+
+        assert_eq!(
+            clock.day_progress_at(RtcDateTimeOffset(RangedU32::new_static::<186_399>()).into()),
+            (86_399, 86_400)
+        );
+    }
+
+    #[test]
+    fn day_progress_at_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<100_000>()),
+        );
+        assert_ok!(clock.set_display_shift(-Duration::HOUR));
+
+        assert_eq!(
+            clock.day_progress_at(RtcDateTimeOffset(RangedU32::new_static::<100_000>()).into()),
+            (82_800, 86_400)
+        );
+    }
+
+    #[test]
+    fn day_progress_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
         );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.day_progress(), (84_180, 86_400));
     }
 
     #[test]
@@ -572,25 +6646,27 @@ mod tests {
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn write_datetime_after_disabled() {
+    fn read_time() {
         let datetime = datetime!(2012-12-21 5:23);
-        let mut clock = assert_ok!(Clock::new(datetime));
-
-        gpio::disable();
+        let clock = assert_ok!(Clock::new(datetime));
 
-        assert_err_eq!(clock.write_datetime(datetime), Error::NotEnabled);
+        assert_ok_eq!(clock.read_time(), datetime.time());
     }
 
     #[test]
+    #[cfg(not(feature = "read-only"))]
     #[cfg_attr(
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn read_date() {
-        let datetime = datetime!(2012-12-21 5:23);
-        let clock = assert_ok!(Clock::new(datetime));
+    fn read_time_no_sub_seconds() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.write_time(time!(6:42:00.123_456_789)));
 
-        assert_ok_eq!(clock.read_date(), datetime.date());
+        let time = assert_ok!(clock.read_time());
+
+        assert_eq!(time, time!(6:42));
+        assert_eq!(time.nanosecond(), 0);
     }
 
     #[test]
@@ -598,18 +6674,18 @@ mod tests {
         not(no_rtc),
         ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
     )]
-    fn read_date_not_enabled() {
+    fn read_time_not_enabled() {
         // Manually enable RTC.
         gpio::enable();
         // Manually construct a `Clock` object, despite RTC being disabled.
         //
         // This is to simulate an RTC failing after `Clock` construction.
-        let clock = Clock {
-            base_date: date!(2012 - 12 - 21),
-            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
-        };
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
 
-        assert_err_eq!(clock.read_date(), Error::NotEnabled);
+        assert_err_eq!(clock.read_time(), Error::NotEnabled);
     }
 
     #[test]
@@ -617,12 +6693,12 @@ mod tests {
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn read_date_after_disabled() {
+    fn read_time_after_disabled() {
         let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
 
         gpio::disable();
 
-        assert_err_eq!(clock.read_date(), Error::NotEnabled);
+        assert_err_eq!(clock.read_time(), Error::NotEnabled);
     }
 
     #[test]
@@ -630,31 +6706,23 @@ mod tests {
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn write_date() {
-        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
-
-        assert_ok!(clock.write_date(date!(2012 - 12 - 21)));
+    fn read_hour_minute() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
 
-        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 0:00));
+        let time = assert_ok!(clock.read_time());
+        assert_ok_eq!(clock.read_hour_minute(), (time.hour(), time.minute()));
     }
 
     #[test]
     #[cfg_attr(
-        not(no_rtc),
-        ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn write_date_not_enabled() {
-        // Manually enable RTC.
-        gpio::enable();
-        // Manually construct a `Clock` object, despite RTC being disabled.
-        //
-        // This is to simulate an RTC failing after `Clock` construction.
-        let mut clock = Clock {
-            base_date: date!(2012 - 12 - 21),
-            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
-        };
+    fn read_hour_minute_unaligned() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23:42)));
 
-        assert_err_eq!(clock.write_date(date!(2012 - 12 - 21)), Error::NotEnabled);
+        let time = assert_ok!(clock.read_time());
+        assert_ok_eq!(clock.read_hour_minute(), (time.hour(), time.minute()));
     }
 
     #[test]
@@ -662,12 +6730,24 @@ mod tests {
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn write_date_after_disabled() {
-        let mut clock = assert_ok!(Clock::new(datetime!(2000-01-01 0:00)));
+    fn read_hour_minute_consecutive() {
+        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
 
-        gpio::disable();
+        // Terminating a transaction early must not corrupt the transactions that follow it.
+        for _ in 0..8 {
+            let before = assert_ok!(clock.read_time());
+            let hour_minute = assert_ok!(clock.read_hour_minute());
+            let hour_minute_again = assert_ok!(clock.read_hour_minute());
+            let after = assert_ok!(clock.read_time());
 
-        assert_err_eq!(clock.write_date(date!(2012 - 12 - 21)), Error::NotEnabled);
+            // The minute may change between reads.
+            let expected = [
+                (before.hour(), before.minute()),
+                (after.hour(), after.minute()),
+            ];
+            assert!(expected.contains(&hour_minute));
+            assert!(expected.contains(&hour_minute_again));
+        }
     }
 
     #[test]
@@ -675,11 +6755,39 @@ mod tests {
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn read_time() {
-        let datetime = datetime!(2012-12-21 5:23);
-        let clock = assert_ok!(Clock::new(datetime));
+    fn read_hour_minute_display_shift() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::HOUR));
 
-        assert_ok_eq!(clock.read_time(), datetime.time());
+        let time = assert_ok!(clock.read_time());
+        assert_eq!(time.hour(), 6);
+        assert_ok_eq!(clock.read_hour_minute(), (time.hour(), time.minute()));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(rtc),
+        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
+    )]
+    fn read_hour_minute_display_shift_unaligned() {
+        // The stored time is aligned to a whole minute, but the displayed time is not.
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+        assert_ok!(clock.set_display_shift(Duration::seconds(30)));
+
+        let time = assert_ok!(clock.read_time());
+        assert_ok_eq!(clock.read_hour_minute(), (time.hour(), time.minute()));
+    }
+
+    #[test]
+    fn read_hour_minute_paused_display_shift() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
+        assert_ok!(clock.set_display_shift(Duration::hours(-6)));
+        assert_ok!(clock.pause_at(RtcDateTimeOffset(RangedU32::new_static::<19_380>())));
+
+        assert_ok_eq!(clock.read_hour_minute(), (23, 23));
     }
 
     #[test]
@@ -687,47 +6795,162 @@ mod tests {
         not(no_rtc),
         ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
     )]
-    fn read_time_not_enabled() {
+    fn read_hour_minute_not_enabled() {
         // Manually enable RTC.
         gpio::enable();
         // Manually construct a `Clock` object, despite RTC being disabled.
         //
         // This is to simulate an RTC failing after `Clock` construction.
-        let clock = Clock {
-            base_date: date!(2012 - 12 - 21),
-            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
-        };
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
 
-        assert_err_eq!(clock.read_time(), Error::NotEnabled);
+        assert_err_eq!(clock.read_hour_minute(), Error::NotEnabled);
     }
 
     #[test]
+    #[cfg(not(feature = "read-only"))]
     #[cfg_attr(
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
     )]
-    fn read_time_after_disabled() {
-        let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+    fn write_time() {
+        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
 
-        gpio::disable();
+        assert_ok!(clock.write_time(time!(22:22)));
 
-        assert_err_eq!(clock.read_time(), Error::NotEnabled);
+        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 22:22));
     }
 
     #[test]
-    #[cfg_attr(
-        not(rtc),
-        ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
-    )]
-    fn write_time() {
-        let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_before_midnight_from_after_midnight() {
+        // The current time is 2012-12-21 00:00:00.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_086_400>());
+        assert_ok_eq!(clock.datetime_at(rtc_offset), datetime!(2012-12-22 0:00));
 
-        assert_ok!(clock.write_time(time!(22:22)));
+        clock.write_time_at(rtc_offset.into(), time!(23:59:59));
 
-        assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 22:22));
+        assert_ok_eq!(
+            clock.datetime_at(rtc_offset),
+            datetime!(2012-12-22 23:59:59)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_after_midnight_from_before_midnight() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_086_399>());
+        assert_ok_eq!(
+            clock.datetime_at(rtc_offset),
+            datetime!(2012-12-21 23:59:59)
+        );
+
+        clock.write_time_at(rtc_offset.into(), time!(0:00));
+
+        assert_ok_eq!(clock.datetime_at(rtc_offset), datetime!(2012-12-21 0:00));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_before_midnight_from_after_midnight_wrapped() {
+        let mut clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<86_399>());
+        assert_ok_eq!(clock.datetime_at(rtc_offset), datetime!(2012-12-22 0:00));
+
+        clock.write_time_at(rtc_offset.into(), time!(23:59:59));
+
+        assert_ok_eq!(
+            clock.datetime_at(rtc_offset),
+            datetime!(2012-12-22 23:59:59)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_min_offset() {
+        let mut clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MIN));
+        let rtc_offset = RtcDateTimeOffset(RangedU32::MIN);
+
+        clock.write_time_at(rtc_offset.into(), time!(23:59:59));
+
+        assert_ok_eq!(
+            clock.datetime_at(rtc_offset),
+            datetime!(2012-12-21 23:59:59)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_max_offset() {
+        let mut clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+        let rtc_offset = RtcDateTimeOffset(RangedU32::MAX);
+
+        clock.write_time_at(rtc_offset.into(), time!(23:59:59));
+
+        assert_ok_eq!(
+            clock.datetime_at(rtc_offset),
+            datetime!(2012-12-21 23:59:59)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_sub_seconds_discarded() {
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_000_000>());
+
+        clock.write_time_at(rtc_offset.into(), time!(5:23:00.999_999_999));
+
+        let datetime = assert_ok!(clock.datetime_at(rtc_offset));
+        assert_eq!(datetime, datetime!(2012-12-21 5:23));
+        assert_eq!(datetime.nanosecond(), 0);
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_sub_seconds_discarded_from_later_time() {
+        // The current time is 2012-12-21 05:23:01.
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<1_019_381>());
+
+        clock.write_time_at(rtc_offset.into(), time!(5:23:00.5));
+
+        assert_ok_eq!(clock.datetime_at(rtc_offset), datetime!(2012-12-21 5:23));
+    }
+
+    #[test]
+    #[cfg(not(feature = "read-only"))]
+    fn write_time_at_after_midnight_from_before_midnight_wrapped() {
+        let mut clock = Clock::from_parts(date!(2012 - 12 - 21), RtcDateTimeOffset(RangedU32::MAX));
+        let rtc_offset = RtcDateTimeOffset(RangedU32::new_static::<86_398>());
+        assert_ok_eq!(
+            clock.datetime_at(rtc_offset),
+            datetime!(2012-12-21 23:59:59)
+        );
+
+        clock.write_time_at(rtc_offset.into(), time!(0:00));
+
+        assert_ok_eq!(clock.datetime_at(rtc_offset), datetime!(2012-12-21 0:00));
     }
 
     #[test]
+    #[cfg(not(feature = "read-only"))]
     #[cfg_attr(
         not(no_rtc),
         ignore = "This test requires the RTC to be disabled. Ensure no RTC is configured and pass `--cfg no_rtc` to enable."
@@ -738,15 +6961,16 @@ mod tests {
         // Manually construct a `Clock` object, despite RTC being disabled.
         //
         // This is to simulate an RTC failing after `Clock` construction.
-        let mut clock = Clock {
-            base_date: date!(2012 - 12 - 21),
-            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
-        };
+        let mut clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        );
 
         assert_err_eq!(clock.write_time(time!(22:22)), Error::NotEnabled);
     }
 
     #[test]
+    #[cfg(not(feature = "read-only"))]
     #[cfg_attr(
         not(rtc),
         ignore = "This test requires a functioning RTC. Ensure an RTC is configured and pass `--cfg rtc` to enable."
@@ -0,0 +1,132 @@
+//! A future that resolves when the stored time advances to the next second.
+
+use crate::{
+    Clock,
+    Error,
+};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll,
+    },
+};
+use time::Time;
+
+/// A future that resolves to the stored time once it advances to the next second.
+///
+/// This is returned by [`Clock::next_second()`]. Each poll reads the stored time from the RTC. The
+/// first poll records the current second, and later polls resolve once a different second is
+/// read.
+///
+/// This future does not arrange for itself to be woken. It must instead be polled frequently by
+/// the executor, such as once per VBlank. If the RTC cannot be read, the future resolves to the
+/// error.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct NextSecond<'a> {
+    /// The `Clock` to read the time from.
+    clock: &'a Clock,
+    /// The time read on the first poll.
+    start: Option<Time>,
+}
+
+impl<'a> NextSecond<'a> {
+    /// Creates a future waiting for the next second of `clock`.
+    pub(crate) fn new(clock: &'a Clock) -> Self {
+        Self { clock, start: None }
+    }
+
+    /// Advances the future's state using a newly read `time`.
+    fn advance(&mut self, time: Result<Time, Error>) -> Poll<Result<Time, Error>> {
+        let time = match time {
+            Ok(time) => time,
+            Err(error) => return Poll::Ready(Err(error)),
+        };
+        match self.start {
+            None => {
+                self.start = Some(time);
+                Poll::Pending
+            }
+            Some(start) if start == time => Poll::Pending,
+            Some(_) => Poll::Ready(Ok(time)),
+        }
+    }
+}
+
+impl Future for NextSecond<'_> {
+    type Output = Result<Time, Error>;
+
+    fn poll(self: Pin<&mut Self>, _context: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let time = this.clock.read_time();
+        this.advance(time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NextSecond;
+    use crate::{
+        date_time::RtcDateTimeOffset,
+        ranged::RangedU32,
+        Clock,
+        Error,
+    };
+    use core::task::Poll;
+    use gba_test::test;
+    use time_macros::{
+        date,
+        time,
+    };
+
+    fn clock() -> Clock {
+        Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        )
+    }
+
+    #[test]
+    fn advance_first_read_pending() {
+        let clock = clock();
+        let mut future = NextSecond::new(&clock);
+
+        assert_eq!(future.advance(Ok(time!(5:23:42))), Poll::Pending);
+    }
+
+    #[test]
+    fn advance_same_second_pending() {
+        let clock = clock();
+        let mut future = NextSecond::new(&clock);
+
+        assert_eq!(future.advance(Ok(time!(5:23:42))), Poll::Pending);
+        assert_eq!(future.advance(Ok(time!(5:23:42))), Poll::Pending);
+    }
+
+    #[test]
+    fn advance_next_second_ready() {
+        let clock = clock();
+        let mut future = NextSecond::new(&clock);
+
+        assert_eq!(future.advance(Ok(time!(5:23:42))), Poll::Pending);
+        assert_eq!(future.advance(Ok(time!(5:23:42))), Poll::Pending);
+        assert_eq!(
+            future.advance(Ok(time!(5:23:43))),
+            Poll::Ready(Ok(time!(5:23:43)))
+        );
+    }
+
+    #[test]
+    fn advance_error_ready() {
+        let clock = clock();
+        let mut future = NextSecond::new(&clock);
+
+        assert_eq!(future.advance(Ok(time!(5:23:42))), Poll::Pending);
+        assert_eq!(
+            future.advance(Err(Error::NotEnabled)),
+            Poll::Ready(Err(Error::NotEnabled))
+        );
+    }
+}
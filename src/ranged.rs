@@ -0,0 +1,223 @@
+//! Integers restricted to a range of values.
+//!
+//! When the `ranged` feature is enabled, these are the types provided by the
+//! [`deranged`](https://crates.io/crates/deranged) crate. Otherwise, minimal equivalents backed by
+//! plain integers are provided here, with range checks performed only in debug builds.
+
+#[cfg(feature = "ranged")]
+pub(crate) use deranged::{
+    RangedU32,
+    RangedU8,
+};
+#[cfg(not(feature = "ranged"))]
+pub(crate) use fallback::{
+    RangedU32,
+    RangedU8,
+};
+
+#[cfg(not(feature = "ranged"))]
+mod fallback {
+    #[cfg(feature = "serde")]
+    use serde::{
+        de,
+        de::Unexpected,
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    macro_rules! ranged {
+        ($type:ident, $internal:ty) => {
+            /// An integer guaranteed to be within the range `MIN..=MAX`.
+            ///
+            /// The range is only checked in debug builds.
+            #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub(crate) struct $type<const MIN: $internal, const MAX: $internal>($internal);
+
+            // Not every method is used by every instantiation.
+            #[allow(dead_code)]
+            impl<const MIN: $internal, const MAX: $internal> $type<MIN, MAX> {
+                pub(crate) const MIN: Self = Self(MIN);
+                pub(crate) const MAX: Self = Self(MAX);
+
+                /// Creates a ranged integer without checking the value.
+                ///
+                /// # Safety
+                /// The value must be within the range `MIN..=MAX`.
+                pub(crate) const unsafe fn new_unchecked(value: $internal) -> Self {
+                    debug_assert!(MIN <= value && value <= MAX);
+                    Self(value)
+                }
+
+                /// Creates a ranged integer if the value is within the range `MIN..=MAX`.
+                pub(crate) const fn new(value: $internal) -> Option<Self> {
+                    if MIN <= value && value <= MAX {
+                        Some(Self(value))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Creates a ranged integer from a value that is checked at compile time.
+                pub(crate) const fn new_static<const VALUE: $internal>() -> Self {
+                    const { assert!(MIN <= VALUE && VALUE <= MAX) };
+                    Self(VALUE)
+                }
+
+                /// Returns the value as a primitive integer.
+                pub(crate) const fn get(self) -> $internal {
+                    self.0
+                }
+
+                /// Converts to a ranged integer with a different range, if the value is within
+                /// it.
+                pub(crate) const fn narrow<const NEW_MIN: $internal, const NEW_MAX: $internal>(
+                    self,
+                ) -> Option<$type<NEW_MIN, NEW_MAX>> {
+                    $type::<NEW_MIN, NEW_MAX>::new(self.0)
+                }
+
+                /// Checked addition, returning `None` if the result is out of range.
+                pub(crate) const fn checked_add(self, rhs: $internal) -> Option<Self> {
+                    match self.0.checked_add(rhs) {
+                        Some(value) => Self::new(value),
+                        None => None,
+                    }
+                }
+
+                /// Checked subtraction, returning `None` if the result is out of range.
+                pub(crate) const fn checked_sub(self, rhs: $internal) -> Option<Self> {
+                    match self.0.checked_sub(rhs) {
+                        Some(value) => Self::new(value),
+                        None => None,
+                    }
+                }
+
+                /// Unchecked addition.
+                ///
+                /// # Safety
+                /// The result must be within the range `MIN..=MAX`.
+                pub(crate) const unsafe fn unchecked_add(self, rhs: $internal) -> Self {
+                    // SAFETY: The caller guarantees the result is within range.
+                    unsafe { Self::new_unchecked(self.0.wrapping_add(rhs)) }
+                }
+
+                /// Unchecked subtraction.
+                ///
+                /// # Safety
+                /// The result must be within the range `MIN..=MAX`.
+                pub(crate) const unsafe fn unchecked_sub(self, rhs: $internal) -> Self {
+                    // SAFETY: The caller guarantees the result is within range.
+                    unsafe { Self::new_unchecked(self.0.wrapping_sub(rhs)) }
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<const MIN: $internal, const MAX: $internal> Serialize for $type<MIN, MAX> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de, const MIN: $internal, const MAX: $internal> Deserialize<'de>
+                for $type<MIN, MAX>
+            {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    Self::new(<$internal>::deserialize(deserializer)?).ok_or_else(|| {
+                        de::Error::invalid_value(
+                            Unexpected::Other("integer"),
+                            &"an integer in the valid range",
+                        )
+                    })
+                }
+            }
+        };
+    }
+
+    ranged!(RangedU8, u8);
+    ranged!(RangedU32, u32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RangedU32,
+        RangedU8,
+    };
+    use crate::date_time::RTC_MAX_SECONDS;
+    use claims::{
+        assert_none,
+        assert_some_eq,
+    };
+    use gba_test::test;
+
+    #[test]
+    fn bounds() {
+        assert_eq!(RangedU32::<0, 86_399>::MIN.get(), 0);
+        assert_eq!(RangedU32::<0, 86_399>::MAX.get(), 86_399);
+    }
+
+    #[test]
+    fn checked_add_in_range() {
+        assert_some_eq!(
+            RangedU32::<0, 86_399>::new_static::<86_000>().checked_add(399),
+            RangedU32::<0, 86_399>::new_static::<86_399>()
+        );
+    }
+
+    #[test]
+    fn checked_add_out_of_range() {
+        assert_none!(RangedU32::<0, 86_399>::new_static::<86_000>().checked_add(400));
+    }
+
+    #[test]
+    fn checked_sub_in_range() {
+        assert_some_eq!(
+            RangedU32::<0, 86_399>::new_static::<42>().checked_sub(42),
+            RangedU32::<0, 86_399>::MIN
+        );
+    }
+
+    #[test]
+    fn checked_sub_out_of_range() {
+        assert_none!(RangedU32::<0, 86_399>::new_static::<42>().checked_sub(43));
+    }
+
+    #[test]
+    fn unchecked_arithmetic() {
+        assert_eq!(
+            unsafe {
+                RangedU32::<0, RTC_MAX_SECONDS>::MAX
+                    .unchecked_sub(RTC_MAX_SECONDS)
+                    .unchecked_add(42)
+            },
+            RangedU32::<0, RTC_MAX_SECONDS>::new_static::<42>()
+        );
+    }
+
+    #[test]
+    fn narrow_in_range() {
+        assert_some_eq!(
+            RangedU8::<0, 99>::new_static::<31>().narrow::<1, 31>(),
+            RangedU8::<1, 31>::new_static::<31>()
+        );
+    }
+
+    #[test]
+    fn narrow_out_of_range() {
+        assert_none!(RangedU8::<0, 99>::new_static::<0>().narrow::<1, 31>());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(RangedU8::<0, 99>::new_static::<12>() < RangedU8::<0, 99>::new_static::<13>());
+    }
+}
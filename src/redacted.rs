@@ -0,0 +1,176 @@
+//! A `Debug` view of a `Clock` that does not reveal the stored date and time.
+
+use crate::Clock;
+use core::{
+    fmt,
+    fmt::{
+        Debug,
+        Formatter,
+    },
+};
+
+/// A view of a [`Clock`] whose [`Debug`] output does not reveal the stored date and time.
+///
+/// This is returned by [`Clock::debug_redacted()`]. Rather than the base date and RTC offset, only
+/// a fingerprint of them is printed. The fingerprint changes whenever the stored date and time is
+/// changed, allowing logged states to be compared without exposing the time itself.
+///
+/// The fingerprint is not cryptographic. It is only intended to keep the date and time from being
+/// casually read from logs.
+#[derive(Clone, Copy)]
+pub struct RedactedClock<'a> {
+    /// The `Clock` being viewed.
+    clock: &'a Clock,
+}
+
+impl<'a> RedactedClock<'a> {
+    /// Creates a redacted view of `clock`.
+    pub(crate) fn new(clock: &'a Clock) -> Self {
+        Self { clock }
+    }
+
+    /// Computes the fingerprint of the viewed `Clock`'s base date and RTC offset.
+    ///
+    /// This is the 32-bit FNV-1a hash of the base date's Julian day followed by the RTC offset,
+    /// each in little-endian byte order.
+    fn fingerprint(&self) -> u32 {
+        const OFFSET_BASIS: u32 = 0x811c_9dc5;
+        const PRIME: u32 = 0x0100_0193;
+
+        self.clock
+            .base_date
+            .to_julian_day()
+            .to_le_bytes()
+            .into_iter()
+            .chain(self.clock.rtc_offset.get().to_le_bytes())
+            .fold(OFFSET_BASIS, |hash, byte| {
+                (hash ^ u32::from(byte)).wrapping_mul(PRIME)
+            })
+    }
+}
+
+impl Debug for RedactedClock<'_> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Clock")
+            .field("fingerprint", &format_args!("{:#010x}", self.fingerprint()))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedactedClock;
+    use crate::{
+        date_time::RtcDateTimeOffset,
+        ranged::RangedU32,
+        Clock,
+    };
+    use core::{
+        fmt,
+        fmt::Write,
+        str,
+    };
+    use gba_test::test;
+    use time_macros::date;
+
+    /// A fixed-size buffer that formatted output can be written to.
+    struct Buffer {
+        bytes: [u8; 64],
+        len: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Self {
+            Self {
+                bytes: [0; 64],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn debug_omits_date_and_time() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let mut buffer = Buffer::new();
+
+        write!(buffer, "{:?}", RedactedClock::new(&clock)).unwrap();
+
+        let output = buffer.as_str();
+        assert!(output.starts_with("Clock { fingerprint: 0x"));
+        assert!(!output.contains("year"));
+        assert!(!output.contains("month"));
+        assert!(!output.contains("day"));
+        assert!(!output.contains("2012"));
+    }
+
+    #[test]
+    fn fingerprint_same_for_same_state() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let other = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+
+        assert_eq!(
+            RedactedClock::new(&clock).fingerprint(),
+            RedactedClock::new(&other).fingerprint()
+        );
+    }
+
+    #[test]
+    fn fingerprint_differs_for_different_offset() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let other = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_001>()),
+        );
+
+        assert_ne!(
+            RedactedClock::new(&clock).fingerprint(),
+            RedactedClock::new(&other).fingerprint()
+        );
+    }
+
+    #[test]
+    fn fingerprint_differs_for_different_date() {
+        let clock = Clock::from_parts(
+            date!(2012 - 12 - 21),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+        let other = Clock::from_parts(
+            date!(2012 - 12 - 22),
+            RtcDateTimeOffset(RangedU32::new_static::<1_000_000>()),
+        );
+
+        assert_ne!(
+            RedactedClock::new(&clock).fingerprint(),
+            RedactedClock::new(&other).fingerprint()
+        );
+    }
+}
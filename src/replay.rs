@@ -0,0 +1,119 @@
+//! A stand-in for a `Clock` that replays previously recorded RTC values.
+
+use crate::{
+    date_time::RtcDateTimeOffset,
+    ranged::RangedU32,
+    Clock,
+    Error,
+};
+use core::slice;
+use time::{
+    Date,
+    PrimitiveDateTime,
+};
+
+/// A stand-in for a [`Clock`] that replays previously recorded RTC values instead of reading from
+/// the hardware.
+///
+/// This is returned by [`Clock::replay_from()`]. Each item is the result [`Clock::read_datetime()`]
+/// would have returned when the RTC read the corresponding recorded value, calculated exactly as a
+/// live `Clock` would. This allows time-dependent logic to be tested deterministically.
+///
+/// Recorded values outside of the RTC's range result in [`Error::DateOutOfRange`].
+#[derive(Debug)]
+pub struct ReplayClock<'a> {
+    /// The `Clock` used to calculate each date and time.
+    clock: Clock,
+    /// The recorded RTC values that have not yet been replayed.
+    recorded_offsets: slice::Iter<'a, u32>,
+}
+
+impl<'a> ReplayClock<'a> {
+    /// Creates a replay of `recorded_offsets`, where midnight on `base_date` is the stored date and
+    /// time at the point the RTC reads `0`.
+    pub(crate) fn new(base_date: Date, recorded_offsets: &'a [u32]) -> Self {
+        Self {
+            clock: Clock::from_parts(base_date, RtcDateTimeOffset(RangedU32::MIN)),
+            recorded_offsets: recorded_offsets.iter(),
+        }
+    }
+}
+
+impl Iterator for ReplayClock<'_> {
+    type Item = Result<PrimitiveDateTime, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &offset = self.recorded_offsets.next()?;
+        Some(
+            RangedU32::new(offset)
+                .ok_or(Error::DateOutOfRange)
+                .and_then(|offset| self.clock.datetime_at(RtcDateTimeOffset(offset))),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.recorded_offsets.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ReplayClock<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplayClock;
+    use crate::{
+        Clock,
+        Error,
+    };
+    use claims::{
+        assert_err_eq,
+        assert_none,
+        assert_ok_eq,
+        assert_some,
+    };
+    use gba_test::test;
+    use time::Date;
+    use time_macros::{
+        date,
+        datetime,
+    };
+
+    #[test]
+    fn replay_sequence() {
+        let mut replay = Clock::replay_from(date!(2012 - 12 - 21), &[0, 1, 19_380, 86_400]);
+
+        assert_ok_eq!(assert_some!(replay.next()), datetime!(2012-12-21 0:00));
+        assert_ok_eq!(assert_some!(replay.next()), datetime!(2012-12-21 0:00:01));
+        assert_ok_eq!(assert_some!(replay.next()), datetime!(2012-12-21 5:23));
+        assert_ok_eq!(assert_some!(replay.next()), datetime!(2012-12-22 0:00));
+        assert_none!(replay.next());
+    }
+
+    #[test]
+    fn replay_empty() {
+        let mut replay = Clock::replay_from(date!(2012 - 12 - 21), &[]);
+
+        assert_none!(replay.next());
+    }
+
+    #[test]
+    fn replay_len() {
+        let replay = Clock::replay_from(date!(2012 - 12 - 21), &[0, 1, 2]);
+
+        assert_eq!(replay.len(), 3);
+    }
+
+    #[test]
+    fn replay_out_of_range() {
+        let mut replay = ReplayClock::new(date!(2012 - 12 - 21), &[u32::MAX]);
+
+        assert_err_eq!(assert_some!(replay.next()), Error::DateOutOfRange);
+    }
+
+    #[test]
+    fn replay_overflow() {
+        let mut replay = ReplayClock::new(Date::MAX, &[86_400]);
+
+        assert_err_eq!(assert_some!(replay.next()), Error::Overflow);
+    }
+}
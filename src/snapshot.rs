@@ -0,0 +1,426 @@
+//! A date and time captured from a `Clock` at a single instant.
+
+use crate::date_time::RtcDateTimeOffset;
+#[cfg(feature = "serde")]
+use core::{
+    fmt,
+    fmt::Formatter,
+    str,
+};
+#[cfg(feature = "serde")]
+use serde::{
+    de,
+    de::{
+        Deserialize,
+        Deserializer,
+        MapAccess,
+        SeqAccess,
+        Unexpected,
+        Visitor,
+    },
+    ser::{
+        Serialize,
+        SerializeStruct,
+        Serializer,
+    },
+};
+use time::{
+    Duration,
+    PrimitiveDateTime,
+};
+
+/// The approximate number of frames displayed by the GBA each second.
+const FRAMES_PER_SECOND: u64 = 60;
+
+/// The date and time read from a [`Clock`](crate::Clock) at a single instant.
+///
+/// A `Snapshot` is a plain value that does not interact with the RTC. It can be freely copied,
+/// compared, and passed around after being captured with
+/// [`Clock::snapshot()`](crate::Clock::snapshot()).
+///
+/// Snapshots are ordered by their date and time.
+///
+/// If the `serde` feature is enabled, snapshots can be serialized and deserialized. Unlike
+/// [`Clock`](crate::Clock), deserializing a `Snapshot` does not interact with the RTC.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Snapshot {
+    /// The date and time read from the `Clock`.
+    pub(crate) datetime: PrimitiveDateTime,
+
+    /// The raw RTC value at the moment the snapshot was captured.
+    pub(crate) rtc_offset: RtcDateTimeOffset,
+}
+
+impl Snapshot {
+    /// Returns the captured date and time.
+    pub fn datetime(&self) -> PrimitiveDateTime {
+        self.datetime
+    }
+
+    /// Returns the amount of time elapsed on the RTC between `earlier` and `self`.
+    ///
+    /// This is calculated using the raw RTC values captured with each snapshot, meaning it is
+    /// unaffected by any changes made to the `Clock`'s stored date and time between the two
+    /// snapshots. `earlier` is assumed to have been captured before `self`. As the RTC's value
+    /// wraps around after 100 years, the result is correct even if the RTC wrapped around between
+    /// the two snapshots.
+    pub fn duration_since(&self, earlier: &Snapshot) -> Duration {
+        (self.rtc_offset - earlier.rtc_offset).into()
+    }
+
+    /// Returns the approximate number of frames elapsed between `self` and the point the RTC
+    /// reads `rtc_offset`.
+    ///
+    /// This assumes 60 frames per second. As the RTC only has a resolution of one second, the
+    /// result is always a multiple of 60.
+    pub(crate) fn frames_until(&self, rtc_offset: RtcDateTimeOffset) -> u64 {
+        u64::from((rtc_offset - self.rtc_offset).get()) * FRAMES_PER_SECOND
+    }
+}
+
+/// Compares the captured date and time against `other`.
+///
+/// The raw RTC value captured with the snapshot is not considered.
+impl PartialEq<PrimitiveDateTime> for Snapshot {
+    fn eq(&self, other: &PrimitiveDateTime) -> bool {
+        self.datetime == *other
+    }
+}
+
+/// Compares `self` against the captured date and time of `other`.
+///
+/// The raw RTC value captured with the snapshot is not considered.
+impl PartialEq<Snapshot> for PrimitiveDateTime {
+    fn eq(&self, other: &Snapshot) -> bool {
+        *self == other.datetime
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Snapshot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut r#struct = serializer.serialize_struct("Snapshot", 2)?;
+        r#struct.serialize_field("datetime", &self.datetime)?;
+        r#struct.serialize_field("rtc_offset", &self.rtc_offset)?;
+        r#struct.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Snapshot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        enum Field {
+            Datetime,
+            RtcOffset,
+        }
+
+        impl<'de> Deserialize<'de> for Field {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct FieldVisitor;
+
+                impl<'de> Visitor<'de> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                        formatter.write_str("`datetime` or `rtc_offset`")
+                    }
+
+                    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            0 => Ok(Field::Datetime),
+                            1 => Ok(Field::RtcOffset),
+                            _ => Err(de::Error::invalid_value(Unexpected::Unsigned(value), &self)),
+                        }
+                    }
+
+                    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            "datetime" => Ok(Field::Datetime),
+                            "rtc_offset" => Ok(Field::RtcOffset),
+                            _ => Err(de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+
+                    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        match value {
+                            b"datetime" => Ok(Field::Datetime),
+                            b"rtc_offset" => Ok(Field::RtcOffset),
+                            _ => {
+                                let utf8_value =
+                                    str::from_utf8(value).unwrap_or("\u{fffd}\u{fffd}\u{fffd}");
+                                Err(de::Error::unknown_field(utf8_value, FIELDS))
+                            }
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        struct SnapshotVisitor;
+
+        impl<'de> Visitor<'de> for SnapshotVisitor {
+            type Value = Snapshot;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("struct Snapshot")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let datetime = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let rtc_offset = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok(Snapshot {
+                    datetime,
+                    rtc_offset,
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut datetime = None;
+                let mut rtc_offset = None;
+
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Datetime => {
+                            if datetime.is_some() {
+                                return Err(de::Error::duplicate_field("datetime"));
+                            }
+                            datetime = Some(map.next_value()?);
+                        }
+                        Field::RtcOffset => {
+                            if rtc_offset.is_some() {
+                                return Err(de::Error::duplicate_field("rtc_offset"));
+                            }
+                            rtc_offset = Some(map.next_value()?);
+                        }
+                    }
+                }
+
+                Ok(Snapshot {
+                    datetime: datetime.ok_or_else(|| de::Error::missing_field("datetime"))?,
+                    rtc_offset: rtc_offset.ok_or_else(|| de::Error::missing_field("rtc_offset"))?,
+                })
+            }
+        }
+
+        const FIELDS: &[&str] = &["datetime", "rtc_offset"];
+        deserializer.deserialize_struct("Snapshot", FIELDS, SnapshotVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Snapshot;
+    use crate::{
+        date_time::{
+            RtcDateTimeOffset,
+            RTC_MAX_SECONDS,
+        },
+        ranged::RangedU32,
+    };
+    #[cfg(feature = "serde")]
+    use claims::{
+        assert_err,
+        assert_ok,
+        assert_ok_eq,
+    };
+    use gba_test::test;
+    use time::Duration;
+    use time_macros::datetime;
+
+    #[test]
+    fn datetime() {
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        };
+
+        assert_eq!(snapshot.datetime(), datetime!(2012-12-21 5:23));
+    }
+
+    #[test]
+    fn eq_primitive_datetime() {
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        };
+
+        assert_eq!(snapshot, datetime!(2012-12-21 5:23));
+        assert_eq!(datetime!(2012-12-21 5:23), snapshot);
+    }
+
+    #[test]
+    fn ne_primitive_datetime() {
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<0>()),
+        };
+
+        assert_ne!(snapshot, datetime!(2012-12-21 5:24));
+        assert_ne!(datetime!(2012-12-21 5:24), snapshot);
+    }
+
+    #[test]
+    fn ordering() {
+        let earlier = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        };
+        let later = Snapshot {
+            datetime: datetime!(2012-12-21 5:24),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<160>()),
+        };
+
+        assert!(earlier < later);
+        assert!(later > earlier);
+        assert_ne!(earlier, later);
+    }
+
+    #[test]
+    fn duration_since() {
+        let earlier = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        };
+        let later = Snapshot {
+            datetime: datetime!(2012-12-21 6:24),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<3_760>()),
+        };
+
+        assert_eq!(later.duration_since(&earlier), Duration::seconds(3_660));
+    }
+
+    #[test]
+    fn duration_since_ignores_datetime() {
+        // The stored date and time was changed between snapshots.
+        let earlier = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        };
+        let later = Snapshot {
+            datetime: datetime!(2000-01-01 0:00),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<142>()),
+        };
+
+        assert_eq!(later.duration_since(&earlier), Duration::seconds(42));
+    }
+
+    #[test]
+    fn duration_since_wrapped() {
+        let earlier = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<RTC_MAX_SECONDS>()),
+        };
+        let later = Snapshot {
+            datetime: datetime!(2012-12-21 5:24),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<59>()),
+        };
+
+        assert_eq!(later.duration_since(&earlier), Duration::seconds(60));
+    }
+
+    #[test]
+    fn frames_until() {
+        let earlier = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        };
+        let later = Snapshot {
+            datetime: datetime!(2012-12-21 5:23:01),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<101>()),
+        };
+
+        assert_eq!(earlier.frames_until(later.rtc_offset), 60);
+    }
+
+    #[test]
+    fn frames_until_same_second() {
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<100>()),
+        };
+
+        assert_eq!(snapshot.frames_until(snapshot.rtc_offset), 0);
+    }
+
+    #[test]
+    fn frames_until_wrapped() {
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<RTC_MAX_SECONDS>()),
+        };
+
+        assert_eq!(
+            snapshot.frames_until(RtcDateTimeOffset(RangedU32::new_static::<1>())),
+            120
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_compact() {
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<42>()),
+        };
+        let mut buffer = [0; 64];
+
+        let bytes = assert_ok!(postcard::to_slice(&snapshot, &mut buffer));
+
+        assert_ok_eq!(postcard::from_bytes::<Snapshot>(bytes), snapshot);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_human_readable() {
+        let snapshot = Snapshot {
+            datetime: datetime!(2012-12-21 5:23),
+            rtc_offset: RtcDateTimeOffset(RangedU32::new_static::<42>()),
+        };
+        let mut buffer = [0; 128];
+
+        let len = assert_ok!(serde_json_core::to_slice(&snapshot, &mut buffer));
+
+        assert_ok_eq!(
+            serde_json_core::from_slice::<Snapshot>(&buffer[..len]),
+            (snapshot, len)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_deserialize_out_of_range_offset() {
+        assert_err!(serde_json_core::from_str::<Snapshot>(
+            r#"{"datetime":[2012,356,5,23,0,0],"rtc_offset":3155760000}"#
+        ));
+    }
+}
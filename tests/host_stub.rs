@@ -0,0 +1,42 @@
+//! Tests of a `Clock` backed by the stubbed RTC.
+//!
+//! These run on the host, and are only built when the `host-stub` feature is enabled.
+
+use claims::{
+    assert_ok,
+    assert_ok_eq,
+};
+use gba_clock::Clock;
+use time::Duration;
+use time_macros::{
+    date,
+    datetime,
+};
+
+#[test]
+fn read_datetime() {
+    let clock = assert_ok!(Clock::new(datetime!(2012-12-21 5:23)));
+
+    // The stubbed RTC never advances.
+    assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+    assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-21 5:23));
+}
+
+#[test]
+fn test_advance_across_midnight() {
+    let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 23:59:30)));
+
+    clock.test_advance(Duration::seconds(60));
+
+    assert_ok_eq!(clock.read_date(), date!(2012 - 12 - 22));
+    assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-22 0:00:30));
+}
+
+#[test]
+fn test_advance_backward() {
+    let mut clock = assert_ok!(Clock::new(datetime!(2012-12-21 0:00:30)));
+
+    clock.test_advance(Duration::seconds(-60));
+
+    assert_ok_eq!(clock.read_datetime(), datetime!(2012-12-20 23:59:30));
+}